@@ -4,7 +4,7 @@ fn part_1(input: &[&str]) -> impl ToString {
     let mut computer = intcode::Computer::new(&input[0]).unwrap();
     computer.set_direct(1, 12);
     computer.set_direct(2, 2);
-    computer.run();
+    computer.run().unwrap();
     computer.get_direct(0)
 }
 
@@ -15,7 +15,7 @@ fn part_2(input: &[&str]) -> impl ToString {
             let mut computer = start.clone();
             computer.set_direct(1, noun);
             computer.set_direct(2, verb);
-            computer.run();
+            computer.run().unwrap();
             if computer.get_direct(0) == 19690720 {
                 return 100 * noun + verb;
             }
@@ -2,18 +2,18 @@ aoc::parts!(1, 2);
 
 fn part_1(input: &[&str]) -> impl ToString {
     let mut computer = intcode::Computer::new(&input[0]).unwrap();
-    computer.run();
-    computer.input(1);
+    computer.run().unwrap();
+    computer.input(1).unwrap();
     let mut last = 0;
-    while let Some(value) = computer.output() {
-        last = value;
+    while computer.state() == intcode::State::Output {
+        last = computer.output().unwrap();
     }
     last
 }
 
 fn part_2(input: &[&str]) -> impl ToString {
     let mut computer = intcode::Computer::new(&input[0]).unwrap();
-    computer.run();
-    computer.input(5);
+    computer.run().unwrap();
+    computer.input(5).unwrap();
     computer.output().unwrap()
 }
@@ -9,11 +9,10 @@ fn part_1(input: &[&str]) -> impl ToString {
         let mut amplifiers = Vec::new();
         amplifiers.resize_with(5, || computer.clone());
         let mut signal = 0;
-        for i in 0..5 {
-            amplifiers[i].run();
-            amplifiers[i].input(permutation[i]);
-            amplifiers[i].input(signal);
-            signal = amplifiers[i].output().unwrap();
+        for (amplifier, phase) in amplifiers.iter_mut().zip(permutation) {
+            amplifier.push_inputs([phase, signal]);
+            amplifier.run_until_blocked().unwrap();
+            signal = amplifier.drain_outputs().next().unwrap();
         }
         if signal > largest {
             largest = signal;
@@ -28,19 +27,18 @@ fn part_2(input: &[&str]) -> impl ToString {
     for permutation in (5..10).permutations(5) {
         let mut amplifiers = Vec::new();
         amplifiers.resize_with(5, || computer.clone());
-        let mut signal = 0;
-        for i in 0..5 {
-            amplifiers[i].run();
-            amplifiers[i].input(permutation[i]);
-            amplifiers[i].input(signal);
-            signal = amplifiers[i].output().unwrap();
+        for (amplifier, phase) in amplifiers.iter_mut().zip(permutation) {
+            amplifier.push_inputs([phase]);
         }
+        let mut signals = vec![0];
         while amplifiers[4].state() != intcode::State::Halted {
             for amplifier in &mut amplifiers {
-                amplifier.input(signal);
-                signal = amplifier.output().unwrap();
+                amplifier.push_inputs(signals);
+                amplifier.run_until_blocked().unwrap();
+                signals = amplifier.drain_outputs().collect();
             }
         }
+        let signal = *signals.last().unwrap();
         if signal > largest {
             largest = signal;
         }
@@ -34,14 +34,14 @@ fn part_2(input: &[&str]) -> impl ToString {
 
 fn paint_hull(starting_panel: u8, input: &str) -> HashMap<Vector, u8> {
     let mut computer = intcode::Computer::new(input).unwrap();
-    computer.run();
+    computer.run().unwrap();
     let mut hull = HashMap::default();
     hull.insert(ZERO, starting_panel);
     let mut current = ZERO;
     let mut mov = v(0, 1);
     while computer.state() != intcode::State::Halted {
         let colour = hull.entry(current).or_insert(0);
-        computer.input(*colour as i64);
+        computer.input(*colour as i64).unwrap();
         *colour = computer.output().unwrap() as u8;
         mov = match computer.output().unwrap() {
             0 => mov.perp(),
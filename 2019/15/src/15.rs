@@ -40,7 +40,7 @@ fn part_2(input: &[&str]) -> impl ToString {
 
 fn map_area(input: &str) -> (Vector, HashMap<Vector, usize>) {
     let mut computer = intcode::Computer::new(input).unwrap();
-    computer.run();
+    computer.run().unwrap();
     let mut distances = HashMap::default();
     let mut unchecked = HashMap::default();
     let mut walls = HashSet::default();
@@ -60,12 +60,12 @@ fn map_area(input: &str) -> (Vector, HashMap<Vector, usize>) {
                     unchecked.insert(new_pos, Vec::from(ORTHOGONAL));
                     *distance = current_dist + 1;
                     stack.push(new_pos);
-                    computer.input(to_input(movement));
+                    computer.input(to_input(movement)).unwrap();
                     computer.output().unwrap();
                     continue 'outer;
                 }
             } else if !walls.contains(&new_pos) {
-                computer.input(to_input(movement));
+                computer.input(to_input(movement)).unwrap();
                 let status = computer.output().unwrap();
                 if status == 2 {
                     oxygen = new_pos;
@@ -82,7 +82,7 @@ fn map_area(input: &str) -> (Vector, HashMap<Vector, usize>) {
         }
         let current_pos = stack.pop().unwrap();
         if let Some(&new_pos) = stack.last() {
-            computer.input(to_input(new_pos - current_pos));
+            computer.input(to_input(new_pos - current_pos)).unwrap();
             computer.output().unwrap();
         }
     }
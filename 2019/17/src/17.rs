@@ -25,55 +25,41 @@ fn part_2(input: &[&str]) -> i64 {
         extract_function(&mut main_routine, 'C'),
     ];
 
+    let mut lines = vec![to_ascii(&main_routine)];
+    lines.extend(functions.iter().map(|function| to_ascii(function)));
+    lines.push("n".to_string());
+
     let mut computer = intcode::Computer::new(&input[0]).unwrap();
     computer.set_direct(0, 2);
-    computer.run();
-
-    while computer.output().is_some() {}
-
-    for (i, part) in main_routine.iter().enumerate() {
-        if let Part::Function(letter) = part {
-            computer.input((*letter as u8) as i64);
-            computer.input(if i == main_routine.len() - 1 { 10 } else { 44 });
-        }
+    for line in lines {
+        computer.push_inputs(line.bytes().chain([b'\n']).map(i64::from));
     }
+    computer.run_until_blocked().unwrap();
+    let dust = computer.drain_outputs().next_back();
+    dust.unwrap()
+}
 
-    for function in functions.iter() {
-        while computer.output().is_some() {}
-        for (i, part) in function.iter().enumerate() {
-            if let Part::Instruction(instruction) = part {
-                computer.input(
-                    (format!("{:?}", instruction.turn).chars().next().unwrap() as u8) as i64,
-                );
-                computer.input(44);
-                for digit in instruction.distance.to_string().chars() {
-                    computer.input((digit as u8) as i64);
-                }
-                computer.input(if i == function.len() - 1 { 10 } else { 44 });
+fn to_ascii(parts: &[Part]) -> String {
+    parts
+        .iter()
+        .map(|part| match part {
+            Part::Function(letter) => letter.to_string(),
+            Part::Instruction(instruction) => {
+                format!("{:?},{}", instruction.turn, instruction.distance)
             }
-        }
-    }
-
-    while computer.output().is_some() {}
-
-    computer.input(b'n' as i64);
-    computer.input(10);
-
-    let mut dust = 0;
-    while let Some(output) = computer.output() {
-        dust = output;
-    }
-    dust
+        })
+        .collect::<Vec<_>>()
+        .join(",")
 }
 
 fn parse(input: &str) -> Grid<char> {
     let mut computer = intcode::Computer::new(input).unwrap();
-    computer.run();
+    computer.run_until_blocked().unwrap();
     let mut view = Vec::new();
     let mut width = 0;
     let mut height = 0;
     let mut x = 0;
-    while let Some(value) = computer.output() {
+    for value in computer.drain_outputs() {
         match value {
             10 => {
                 if x > 1 {
@@ -2,7 +2,7 @@ aoc::parts!(1, 2);
 
 fn part_1(input: &[&str]) -> impl ToString {
     let mut computer = intcode::Computer::new(&input[0]).unwrap();
-    computer.run();
+    computer.run().unwrap();
     let mut total = 0;
     for y in 0..50 {
         for x in 0..50 {
@@ -14,7 +14,7 @@ fn part_1(input: &[&str]) -> impl ToString {
 
 fn part_2(input: &[&str]) -> i64 {
     let mut computer = intcode::Computer::new(&input[0]).unwrap();
-    computer.run();
+    computer.run().unwrap();
     let mut start_x = 0;
     let mut y = 0;
     loop {
@@ -34,7 +34,7 @@ fn part_2(input: &[&str]) -> i64 {
 
 fn affected(computer: &intcode::Computer, x: i64, y: i64) -> bool {
     let mut computer = computer.clone();
-    computer.input(x);
-    computer.input(y);
+    computer.input(x).unwrap();
+    computer.input(y).unwrap();
     computer.output().unwrap() == 1
 }
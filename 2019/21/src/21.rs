@@ -14,15 +14,18 @@ fn part_2(input: &[&str]) -> i64 {
 
 fn springbot(input: &[&str], instructions: &[&str]) -> i64 {
     let mut computer = intcode::Computer::new(&input[0]).unwrap();
-    computer.run();
-    while computer.output().is_some() {}
+    computer.run().unwrap();
+    while computer.state() == intcode::State::Output {
+        computer.output().unwrap();
+    }
     for instruction in instructions {
         for c in instruction.chars() {
-            computer.input((c as u8) as i64);
+            computer.input((c as u8) as i64).unwrap();
         }
-        computer.input(10);
+        computer.input(10).unwrap();
     }
-    while let Some(value) = computer.output() {
+    while computer.state() == intcode::State::Output {
+        let value = computer.output().unwrap();
         if value >= 1 << 8 {
             return value;
         }
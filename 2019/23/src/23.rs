@@ -32,11 +32,10 @@ struct Network {
 
 impl Network {
     fn new(input: &[&str]) -> Network {
-        let mut computer = intcode::Computer::new(&input[0]).unwrap();
-        computer.run();
+        let computer = intcode::Computer::new(&input[0]).unwrap();
         let mut computers = [(); 50].map(|_| computer.clone());
         for (i, computer) in computers.iter_mut().enumerate() {
-            computer.input(i as i64);
+            computer.push_inputs([i as i64]);
         }
         Network {
             computers,
@@ -48,17 +47,17 @@ impl Network {
     fn update(&mut self) -> Option<Vector> {
         let mut updated = false;
         for (i, computer) in self.computers.iter_mut().enumerate() {
-            if computer.state() == intcode::State::Input {
-                if let Some(pos) = self.packets[i].pop_front() {
-                    updated = true;
-                    computer.input(pos.x);
-                    computer.input(pos.y);
-                } else {
-                    computer.input(-1);
-                }
-            } else if computer.state() == intcode::State::Output {
-                let j = computer.output().unwrap() as usize;
-                let packet = Vector::new(computer.output().unwrap(), computer.output().unwrap());
+            if self.packets[i].is_empty() {
+                computer.push_inputs([-1]);
+            } else {
+                updated = true;
+                computer.push_inputs(self.packets[i].drain(..).flat_map(|pos| [pos.x, pos.y]));
+            }
+            computer.run_until_blocked().unwrap();
+            let outputs: Vec<i64> = computer.drain_outputs().collect();
+            for packet in outputs.chunks(3) {
+                let j = packet[0] as usize;
+                let packet = Vector::new(packet[1], packet[2]);
                 if j == 255 {
                     self.nat = packet;
                 } else {
@@ -4,18 +4,19 @@ use std::io;
 
 fn part_1(input: &[&str]) -> impl ToString {
     let mut computer = intcode::Computer::new(&input[0]).unwrap();
-    computer.run();
+    computer.run().unwrap();
     while computer.state() != intcode::State::Halted {
-        while let Some(value) = computer.output() {
+        while computer.state() == intcode::State::Output {
+            let value = computer.output().unwrap();
             print!("{}", (value as u8) as char);
         }
         if computer.state() == intcode::State::Input {
             let mut line = String::new();
             io::stdin().read_line(&mut line).unwrap();
             for c in line.trim().chars() {
-                computer.input((c as u8) as i64);
+                computer.input((c as u8) as i64).unwrap();
             }
-            computer.input(10);
+            computer.input(10).unwrap();
         }
     }
     "Finished."
@@ -0,0 +1,456 @@
+//! Assembly of intcode programs, using the syntax of [`disasm`](crate::disasm) listings.
+//!
+//! Each line holds any number of `label:` definitions followed by an optional statement,
+//! and everything after a `;` is a comment. A statement is either an instruction,
+//! such as `add #5, 100, @-2`, or a `data` directive listing values to place in memory,
+//! such as `data 1, -2, end, "text\n"`, where strings stand for their ASCII codes.
+//! Wherever a value is expected, labels and integers can be combined with `+` and `-`.
+
+use std::{collections::HashMap, fmt};
+
+use crate::{
+    disasm::{Instruction, Parameter},
+    ParameterMode,
+};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Error {
+    pub line: usize,
+    pub kind: ErrorKind,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ErrorKind {
+    UnknownMnemonic(String),
+    WrongOperandCount { expected: usize, found: usize },
+    InvalidOperand(String),
+    InvalidLabel(String),
+    DuplicateLabel(String),
+    UndefinedLabel(String),
+    ImmediateDestination,
+    Overflow,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match &self.kind {
+            ErrorKind::UnknownMnemonic(mnemonic) => write!(f, "unknown mnemonic `{mnemonic}`"),
+            ErrorKind::WrongOperandCount { expected, found } => {
+                write!(f, "expected {expected} operands but found {found}")
+            }
+            ErrorKind::InvalidOperand(operand) => write!(f, "invalid operand `{operand}`"),
+            ErrorKind::InvalidLabel(label) => write!(f, "invalid label `{label}`"),
+            ErrorKind::DuplicateLabel(label) => write!(f, "label `{label}` defined twice"),
+            ErrorKind::UndefinedLabel(label) => write!(f, "label `{label}` not defined"),
+            ErrorKind::ImmediateDestination => {
+                write!(f, "destination parameter in immediate mode")
+            }
+            ErrorKind::Overflow => write!(f, "value does not fit in 64 bits"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Assembles `source` into a memory image, which can be loaded with
+/// [`Computer::from_memory`](crate::Computer::from_memory),
+/// or joined with commas and loaded with [`Computer::new`](crate::Computer::new).
+pub fn assemble(source: &str) -> Result<Vec<i64>, Error> {
+    let mut labels = HashMap::new();
+    let mut statements = Vec::new();
+    let mut address = 0;
+    for (i, line) in source.lines().enumerate() {
+        let error = |kind| Error { line: i + 1, kind };
+        let mut text = split_outside_quotes(line, ';')[0].trim();
+        while let Some((label, rest)) = text.split_once(':') {
+            let label = label.trim();
+            if label.contains(['"', ',']) {
+                break;
+            }
+            if !is_identifier(label) {
+                return Err(error(ErrorKind::InvalidLabel(label.to_string())));
+            }
+            if labels.insert(label, address as i64).is_some() {
+                return Err(error(ErrorKind::DuplicateLabel(label.to_string())));
+            }
+            text = rest.trim();
+        }
+        if text.is_empty() {
+            continue;
+        }
+        let (mnemonic, operands) = text.split_once(char::is_whitespace).unwrap_or((text, ""));
+        let operands: Vec<&str> = if operands.trim().is_empty() {
+            vec![]
+        } else {
+            split_outside_quotes(operands, ',')
+                .into_iter()
+                .map(str::trim)
+                .collect()
+        };
+        let statement = if mnemonic == "data" {
+            let mut values = Vec::new();
+            for operand in operands {
+                if operand.starts_with('"') {
+                    let string = parse_string(operand)
+                        .ok_or_else(|| error(ErrorKind::InvalidOperand(operand.to_string())))?;
+                    values.extend(
+                        string
+                            .bytes()
+                            .map(|b| vec![(false, Atom::Number(b as i64))]),
+                    );
+                } else {
+                    values
+                        .push(parse_expr(operand).ok_or_else(|| {
+                            error(ErrorKind::InvalidOperand(operand.to_string()))
+                        })?);
+                }
+            }
+            address += values.len();
+            Statement::Data(values)
+        } else {
+            let expected = operand_count(mnemonic)
+                .ok_or_else(|| error(ErrorKind::UnknownMnemonic(mnemonic.to_string())))?;
+            if operands.len() != expected {
+                return Err(error(ErrorKind::WrongOperandCount {
+                    expected,
+                    found: operands.len(),
+                }));
+            }
+            let operands = operands
+                .into_iter()
+                .map(|operand| {
+                    let (mode, expr) = if let Some(expr) = operand.strip_prefix('#') {
+                        (ParameterMode::Immediate, expr)
+                    } else if let Some(expr) = operand.strip_prefix('@') {
+                        (ParameterMode::Relative, expr)
+                    } else {
+                        (ParameterMode::Position, operand)
+                    };
+                    let expr = parse_expr(expr)
+                        .ok_or_else(|| error(ErrorKind::InvalidOperand(operand.to_string())))?;
+                    Ok((mode, expr))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            address += expected + 1;
+            Statement::Instruction(mnemonic, operands)
+        };
+        statements.push((i + 1, statement));
+    }
+
+    let mut memory = Vec::with_capacity(address);
+    for (line, statement) in statements {
+        let error = |kind| Error { line, kind };
+        let resolve = |expr: &Expr| {
+            let mut total = 0i64;
+            for (negative, atom) in expr {
+                let value = match atom {
+                    Atom::Number(value) => *value,
+                    Atom::Label(label) => *labels
+                        .get(label)
+                        .ok_or_else(|| error(ErrorKind::UndefinedLabel(label.to_string())))?,
+                };
+                total = if *negative {
+                    total.checked_sub(value)
+                } else {
+                    total.checked_add(value)
+                }
+                .ok_or_else(|| error(ErrorKind::Overflow))?;
+            }
+            Ok(total)
+        };
+        match statement {
+            Statement::Data(values) => {
+                for value in &values {
+                    memory.push(resolve(value)?);
+                }
+            }
+            Statement::Instruction(mnemonic, operands) => {
+                let mut parameters = Vec::with_capacity(operands.len());
+                for (mode, expr) in &operands {
+                    parameters.push(Parameter {
+                        mode: *mode,
+                        value: resolve(expr)?,
+                    });
+                }
+                let instruction = build(mnemonic, &parameters);
+                if instruction.destination().map(|p| p.mode) == Some(ParameterMode::Immediate) {
+                    return Err(error(ErrorKind::ImmediateDestination));
+                }
+                memory.extend(instruction.encode());
+            }
+        }
+    }
+    Ok(memory)
+}
+
+enum Statement<'a> {
+    Instruction(&'a str, Vec<(ParameterMode, Expr<'a>)>),
+    Data(Vec<Expr<'a>>),
+}
+
+type Expr<'a> = Vec<(bool, Atom<'a>)>;
+
+enum Atom<'a> {
+    Number(i64),
+    Label(&'a str),
+}
+
+fn operand_count(mnemonic: &str) -> Option<usize> {
+    match mnemonic {
+        "add" | "mul" | "lt" | "eq" => Some(3),
+        "jt" | "jf" => Some(2),
+        "in" | "out" | "arb" => Some(1),
+        "hlt" => Some(0),
+        _ => None,
+    }
+}
+
+fn build(mnemonic: &str, p: &[Parameter]) -> Instruction {
+    match mnemonic {
+        "add" => Instruction::Add(p[0], p[1], p[2]),
+        "mul" => Instruction::Mul(p[0], p[1], p[2]),
+        "in" => Instruction::Input(p[0]),
+        "out" => Instruction::Output(p[0]),
+        "jt" => Instruction::JumpIfTrue(p[0], p[1]),
+        "jf" => Instruction::JumpIfFalse(p[0], p[1]),
+        "lt" => Instruction::LessThan(p[0], p[1], p[2]),
+        "eq" => Instruction::Equals(p[0], p[1], p[2]),
+        "arb" => Instruction::AdjustRelativeBase(p[0]),
+        _ => Instruction::Halt,
+    }
+}
+
+fn parse_expr(text: &str) -> Option<Expr<'_>> {
+    let mut expr = Vec::new();
+    let mut rest = text.trim();
+    let mut negative = false;
+    if let Some(stripped) = rest.strip_prefix('-') {
+        negative = true;
+        rest = stripped;
+    }
+    loop {
+        let end = rest.find(['+', '-']).unwrap_or(rest.len());
+        let atom = rest[..end].trim();
+        let atom = if let Ok(value) = atom.parse() {
+            Atom::Number(value)
+        } else if is_identifier(atom) {
+            Atom::Label(atom)
+        } else if let (true, Ok(value)) = (negative && expr.is_empty(), format!("-{atom}").parse())
+        {
+            // the leading sign is parsed with the number, since `i64::MIN` has no positive counterpart
+            negative = false;
+            Atom::Number(value)
+        } else {
+            return None;
+        };
+        expr.push((negative, atom));
+        if end == rest.len() {
+            return Some(expr);
+        }
+        negative = rest.as_bytes()[end] == b'-';
+        rest = &rest[end + 1..];
+    }
+}
+
+fn parse_string(text: &str) -> Option<String> {
+    let inner = text.strip_prefix('"')?.strip_suffix('"')?;
+    let mut string = String::new();
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        string.push(match c {
+            '\\' => match chars.next()? {
+                'n' => '\n',
+                't' => '\t',
+                c @ ('\\' | '"') => c,
+                _ => return None,
+            },
+            '"' => return None,
+            c if c.is_ascii() => c,
+            _ => return None,
+        });
+    }
+    Some(string)
+}
+
+fn is_identifier(text: &str) -> bool {
+    let mut chars = text.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn split_outside_quotes(text: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let (mut quoted, mut escaped) = (false, false);
+    for (i, c) in text.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            c if c == separator && !quoted => {
+                parts.push(&text[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&text[start..]);
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Computer, State};
+
+    fn error(source: &str) -> Error {
+        assemble(source).unwrap_err()
+    }
+
+    #[test]
+    fn factorial() {
+        let memory = assemble(
+            "
+                in n
+            loop:
+                jf n, #end
+                mul acc, n, acc
+                add n, #-1, n
+                jt #1, #loop
+            end:
+                out acc ; the result
+                hlt
+            n:  data 0
+            acc: data 1
+            ",
+        )
+        .unwrap();
+        let mut computer = Computer::from_memory(memory);
+        computer.run().unwrap();
+        computer.input(5).unwrap();
+        assert_eq!(computer.output().unwrap(), 120);
+        assert_eq!(computer.state(), State::Halted);
+    }
+
+    #[test]
+    fn relative_mode_and_strings() {
+        let memory = assemble(
+            r#"
+                arb #text
+            loop:
+                jf @0, #end
+                out @0
+                arb #1
+                jt #1, #loop
+            end: hlt
+            text: data "hi\n", 0
+            "#,
+        )
+        .unwrap();
+        let mut computer = Computer::from_memory(memory);
+        computer.run().unwrap();
+        let mut text = String::new();
+        while computer.state() == State::Output {
+            text.push(computer.output().unwrap() as u8 as char);
+        }
+        assert_eq!(text, "hi\n");
+        assert_eq!(computer.state(), State::Halted);
+    }
+
+    #[test]
+    fn expressions() {
+        let memory = assemble("a: data a + 2, b - 1\nb: data -b, \"a;b\", 3-1+1").unwrap();
+        assert_eq!(memory, [2, 1, -2, 97, 59, 98, 3]);
+        let memory =
+            assemble("data -9223372036854775808, -9223372036854775807 - 1, -1 - a\na:").unwrap();
+        assert_eq!(memory, [i64::MIN, i64::MIN, -4]);
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(
+            error("hlt\ndata 9223372036854775807 + 1"),
+            Error {
+                line: 2,
+                kind: ErrorKind::Overflow,
+            }
+        );
+        assert_eq!(
+            error("a: data 0 - a - 9223372036854775807 - 1 - 1").kind,
+            ErrorKind::Overflow
+        );
+        assert_eq!(
+            error("add #9223372036854775807 + 1, 0, 0").kind,
+            ErrorKind::Overflow
+        );
+    }
+
+    #[test]
+    fn unknown_mnemonic() {
+        assert_eq!(
+            error("hlt\nfoo 1"),
+            Error {
+                line: 2,
+                kind: ErrorKind::UnknownMnemonic("foo".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn wrong_operand_count() {
+        assert_eq!(
+            error("add 1, 2").kind,
+            ErrorKind::WrongOperandCount {
+                expected: 3,
+                found: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn invalid_operand() {
+        assert_eq!(
+            error("out #1x").kind,
+            ErrorKind::InvalidOperand("#1x".to_string())
+        );
+        assert_eq!(
+            error("data \"open").kind,
+            ErrorKind::InvalidOperand("\"open".to_string())
+        );
+    }
+
+    #[test]
+    fn invalid_label() {
+        assert_eq!(
+            error("1abc: hlt").kind,
+            ErrorKind::InvalidLabel("1abc".to_string())
+        );
+    }
+
+    #[test]
+    fn duplicate_label() {
+        assert_eq!(
+            error("a: hlt\na: hlt"),
+            Error {
+                line: 2,
+                kind: ErrorKind::DuplicateLabel("a".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn undefined_label() {
+        assert_eq!(
+            error("jt #1, #nowhere").kind,
+            ErrorKind::UndefinedLabel("nowhere".to_string())
+        );
+    }
+
+    #[test]
+    fn immediate_destination() {
+        assert_eq!(error("add 1, 2, #3").kind, ErrorKind::ImmediateDestination);
+    }
+}
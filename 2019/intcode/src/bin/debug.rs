@@ -0,0 +1,214 @@
+use std::{
+    env, fs,
+    io::{self, BufRead, Write},
+};
+
+use intcode::{
+    debug::{Debugger, Stop},
+    disasm::Instruction,
+    Computer,
+};
+
+const HELP: &str = "\
+commands:
+  load <path>          load a program, resetting the debugger
+  step [n]             execute n instructions (default 1)
+  continue             run until a breakpoint, watchpoint, input request or halt
+  break <address>      break before executing the instruction at an address
+  breakop <opcode>     break before executing any instruction with an opcode
+  watch <address>      stop after any write to an address
+  delete <address>     remove the breakpoint and watchpoint at an address
+  deleteop <opcode>    remove the breakpoint on an opcode
+  ascii <text>         queue text followed by a newline as input
+  input <value>...     queue integers as input
+  state                show the state, instruction pointer and relative base
+  mem <address> [n]    show n words of memory (default 1)
+  set <address> <value>
+                       write a word of memory
+  dis [address] [n]    disassemble n instructions (default 10) from an address
+  trace                show the most recently executed instructions
+  quit";
+
+fn main() {
+    let mut debugger = None;
+    if let Some(path) = env::args().nth(1) {
+        debugger = load(&path);
+    }
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!("> ");
+        io::stdout().flush().unwrap();
+        let Some(Ok(line)) = lines.next() else {
+            break;
+        };
+        let (command, args) = line.trim().split_once(' ').unwrap_or((line.trim(), ""));
+        match command {
+            "" => {}
+            "help" => println!("{HELP}"),
+            "quit" => break,
+            "load" => {
+                if let Some(loaded) = load(args.trim()) {
+                    debugger = Some(loaded);
+                }
+            }
+            _ => match &mut debugger {
+                Some(debugger) => {
+                    if let Err(message) = execute(debugger, command, args) {
+                        println!("{message}");
+                    }
+                }
+                None => println!("no program loaded"),
+            },
+        }
+    }
+}
+
+fn load(path: &str) -> Option<Debugger> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(error) => {
+            println!("could not read {path}: {error}");
+            return None;
+        }
+    };
+    match Computer::new(text.trim()) {
+        Ok(computer) => {
+            println!("loaded {} words", computer.memory().len());
+            Some(Debugger::new(computer))
+        }
+        Err(error) => {
+            println!("could not parse {path}: {error}");
+            None
+        }
+    }
+}
+
+fn execute(debugger: &mut Debugger, command: &str, text: &str) -> Result<(), String> {
+    let args: Vec<&str> = text.split_whitespace().collect();
+    let arg = |i: usize, default: Option<i64>| -> Result<i64, String> {
+        match args.get(i) {
+            Some(arg) => arg.parse().map_err(|_| format!("invalid number `{arg}`")),
+            None => default.ok_or_else(|| "missing argument".to_string()),
+        }
+    };
+    let address = |i: usize, default: Option<i64>| -> Result<usize, String> {
+        usize::try_from(arg(i, default)?).map_err(|_| "negative address".to_string())
+    };
+    match command {
+        "step" => {
+            let mut stop = Stop::Step;
+            for _ in 0..arg(0, Some(1))? {
+                stop = debugger.step();
+                if stop != Stop::Step {
+                    break;
+                }
+            }
+            report(debugger, stop);
+        }
+        "continue" => {
+            let stop = debugger.resume();
+            report(debugger, stop);
+        }
+        "break" => debugger.add_breakpoint(address(0, None)?),
+        "breakop" => debugger.add_opcode_breakpoint(arg(0, None)?),
+        "watch" => debugger.add_watchpoint(address(0, None)?),
+        "delete" => {
+            let address = address(0, None)?;
+            let breakpoint = debugger.remove_breakpoint(address);
+            let watchpoint = debugger.remove_watchpoint(address);
+            if !breakpoint && !watchpoint {
+                return Err(format!("nothing set at {address}"));
+            }
+        }
+        "deleteop" => {
+            let opcode = arg(0, None)?;
+            if !debugger.remove_opcode_breakpoint(opcode) {
+                return Err(format!("no breakpoint on opcode {opcode}"));
+            }
+        }
+        "ascii" => debugger.push_ascii(&format!("{text}\n")),
+        "input" => {
+            for i in 0..args.len() {
+                debugger.push_input(arg(i, None)?);
+            }
+        }
+        "state" => {
+            let computer = debugger.computer();
+            println!(
+                "{:?} ip={} rb={}",
+                computer.state(),
+                computer.instruction_pointer(),
+                computer.relative_base()
+            );
+            if let Some(instruction) = debugger.next_instruction() {
+                println!("next: {instruction}");
+            }
+        }
+        "mem" => {
+            let start = address(0, None)?;
+            for address in start..start + address(1, Some(1))? {
+                println!("{address:>6}  {}", debugger.computer().get_direct(address));
+            }
+        }
+        "set" => {
+            let (address, value) = (address(0, None)?, arg(1, None)?);
+            debugger.computer_mut().set_direct(address, value);
+        }
+        "dis" => {
+            let memory = debugger.computer().memory();
+            let ip = debugger.computer().instruction_pointer() as i64;
+            let mut address = address(0, Some(ip))?;
+            for _ in 0..arg(1, Some(10))? {
+                match Instruction::decode(memory, address) {
+                    Some(instruction) => {
+                        println!("{address:>6}  {instruction}");
+                        address += instruction.size();
+                    }
+                    None => {
+                        match memory.get(address) {
+                            Some(value) => println!("{address:>6}  data {value}"),
+                            None => break,
+                        }
+                        address += 1;
+                    }
+                }
+            }
+        }
+        "trace" => {
+            for entry in debugger.trace() {
+                println!(
+                    "{:>6}  rb={:<6} {}",
+                    entry.address, entry.relative_base, entry.instruction
+                );
+            }
+        }
+        _ => return Err(format!("unknown command `{command}`, try `help`")),
+    }
+    Ok(())
+}
+
+fn report(debugger: &mut Debugger, stop: Stop) {
+    let outputs = debugger.take_outputs();
+    if outputs.iter().all(|value| (0..128).contains(value)) {
+        print!(
+            "{}",
+            outputs.iter().map(|&v| v as u8 as char).collect::<String>()
+        );
+    } else {
+        for value in outputs {
+            println!("output: {value}");
+        }
+    }
+    let ip = debugger.computer().instruction_pointer();
+    match stop {
+        Stop::Step => println!("stepped to {ip}"),
+        Stop::Breakpoint(address) => println!("breakpoint at {address}"),
+        Stop::Watchpoint { address, old, new } => {
+            println!("watchpoint at {address}: {old} -> {new}, stopped at {ip}")
+        }
+        Stop::Input => println!("waiting for input at {ip}"),
+        Stop::Halted => println!("halted"),
+        Stop::Error(error) => println!("error: {error}"),
+    }
+}
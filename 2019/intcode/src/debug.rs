@@ -0,0 +1,351 @@
+//! Stepping through intcode programs with breakpoints, watchpoints and an execution trace.
+
+use std::collections::{BTreeSet, VecDeque};
+
+use crate::{
+    disasm::{Instruction, Parameter},
+    Computer, Error, ParameterMode, State,
+};
+
+/// Why the debugger stopped running the program.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Stop {
+    /// A single instruction was executed.
+    Step,
+    /// The next instruction is at a breakpoint address or has a breakpoint opcode.
+    Breakpoint(usize),
+    /// The last instruction wrote to a watched address.
+    Watchpoint {
+        address: usize,
+        old: i64,
+        new: i64,
+    },
+    /// The next instruction needs input, but none is queued.
+    Input,
+    Halted,
+    Error(Error),
+}
+
+/// An executed instruction, along with the state before it ran.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TraceEntry {
+    pub address: usize,
+    pub relative_base: i64,
+    pub instruction: Instruction,
+}
+
+#[derive(Clone)]
+pub struct Debugger {
+    computer: Computer,
+    breakpoints: BTreeSet<usize>,
+    opcode_breakpoints: BTreeSet<i64>,
+    watchpoints: BTreeSet<usize>,
+    trace: VecDeque<TraceEntry>,
+    trace_capacity: usize,
+}
+
+impl Debugger {
+    pub fn new(computer: Computer) -> Debugger {
+        Debugger {
+            computer,
+            breakpoints: BTreeSet::new(),
+            opcode_breakpoints: BTreeSet::new(),
+            watchpoints: BTreeSet::new(),
+            trace: VecDeque::new(),
+            trace_capacity: 64,
+        }
+    }
+
+    pub fn computer(&self) -> &Computer {
+        &self.computer
+    }
+
+    pub fn computer_mut(&mut self) -> &mut Computer {
+        &mut self.computer
+    }
+
+    pub fn into_computer(self) -> Computer {
+        self.computer
+    }
+
+    pub fn push_input(&mut self, value: i64) {
+        self.computer.push_inputs([value]);
+    }
+
+    /// Queues the ASCII codes of `text` as input.
+    pub fn push_ascii(&mut self, text: &str) {
+        self.computer.push_inputs(text.bytes().map(i64::from));
+    }
+
+    /// Removes and returns every output produced since the last call.
+    pub fn take_outputs(&mut self) -> Vec<i64> {
+        self.computer.drain_outputs().collect()
+    }
+
+    pub fn add_breakpoint(&mut self, address: usize) {
+        self.breakpoints.insert(address);
+    }
+
+    pub fn remove_breakpoint(&mut self, address: usize) -> bool {
+        self.breakpoints.remove(&address)
+    }
+
+    pub fn breakpoints(&self) -> &BTreeSet<usize> {
+        &self.breakpoints
+    }
+
+    pub fn add_opcode_breakpoint(&mut self, opcode: i64) {
+        self.opcode_breakpoints.insert(opcode);
+    }
+
+    pub fn remove_opcode_breakpoint(&mut self, opcode: i64) -> bool {
+        self.opcode_breakpoints.remove(&opcode)
+    }
+
+    pub fn opcode_breakpoints(&self) -> &BTreeSet<i64> {
+        &self.opcode_breakpoints
+    }
+
+    pub fn add_watchpoint(&mut self, address: usize) {
+        self.watchpoints.insert(address);
+    }
+
+    pub fn remove_watchpoint(&mut self, address: usize) -> bool {
+        self.watchpoints.remove(&address)
+    }
+
+    pub fn watchpoints(&self) -> &BTreeSet<usize> {
+        &self.watchpoints
+    }
+
+    /// Returns the most recently executed instructions, oldest first.
+    pub fn trace(&self) -> impl Iterator<Item = &TraceEntry> {
+        self.trace.iter()
+    }
+
+    /// Sets how many executed instructions the trace keeps, discarding the oldest if necessary.
+    pub fn set_trace_capacity(&mut self, capacity: usize) {
+        self.trace_capacity = capacity;
+        while self.trace.len() > capacity {
+            self.trace.pop_front();
+        }
+    }
+
+    /// Returns the instruction at the instruction pointer, or `None` if it is not valid.
+    pub fn next_instruction(&self) -> Option<Instruction> {
+        let address = self.computer.instruction_pointer;
+        let words: Vec<i64> = (address..address + 4)
+            .map(|address| self.computer.get_direct(address))
+            .collect();
+        Instruction::decode(&words, 0)
+    }
+
+    /// Executes a single instruction, including any input or output it performs.
+    pub fn step(&mut self) -> Stop {
+        if let Some(error) = self.computer.fault {
+            return Stop::Error(error);
+        }
+        if self.computer.state == State::Halted {
+            return Stop::Halted;
+        }
+        let address = self.computer.instruction_pointer;
+        let instruction = self.next_instruction();
+        if matches!(instruction, Some(Instruction::Input(_))) && self.computer.inputs.is_empty() {
+            return Stop::Input;
+        }
+        if let Some(instruction) = instruction.filter(|_| self.trace_capacity > 0) {
+            if self.trace.len() == self.trace_capacity {
+                self.trace.pop_front();
+            }
+            self.trace.push_back(TraceEntry {
+                address,
+                relative_base: self.computer.relative_base,
+                instruction,
+            });
+        }
+        let written = instruction
+            .and_then(|instruction| instruction.destination())
+            .and_then(|parameter| self.address(parameter))
+            .map(|address| (address, self.computer.get_direct(address)));
+
+        let result = self
+            .computer
+            .step()
+            .and_then(|()| match self.computer.state {
+                State::Input => match self.computer.inputs.pop_front() {
+                    Some(value) => self.computer.provide_input(value),
+                    None => Ok(()),
+                },
+                State::Output => {
+                    let value = self.computer.take_output()?;
+                    self.computer.outputs.push_back(value);
+                    Ok(())
+                }
+                _ => Ok(()),
+            });
+        if let Err(error) = result {
+            return Stop::Error(error);
+        }
+
+        match self.computer.state {
+            State::Halted => Stop::Halted,
+            State::Input => Stop::Input,
+            _ => match written {
+                Some((address, old)) if self.watchpoints.contains(&address) => Stop::Watchpoint {
+                    address,
+                    old,
+                    new: self.computer.get_direct(address),
+                },
+                _ => Stop::Step,
+            },
+        }
+    }
+
+    /// Executes instructions until a breakpoint, watchpoint or state change stops the program.
+    ///
+    /// At least one instruction is executed, so a breakpoint at the instruction pointer is passed over.
+    pub fn resume(&mut self) -> Stop {
+        let mut stop = self.step();
+        while stop == Stop::Step {
+            let address = self.computer.instruction_pointer;
+            if self.breakpoints.contains(&address)
+                || self
+                    .opcode_breakpoints
+                    .contains(&(self.computer.get_direct(address) % 100))
+            {
+                return Stop::Breakpoint(address);
+            }
+            stop = self.step();
+        }
+        stop
+    }
+
+    fn address(&self, parameter: Parameter) -> Option<usize> {
+        let address = match parameter.mode {
+            ParameterMode::Position => parameter.value,
+            ParameterMode::Immediate => return None,
+            ParameterMode::Relative => self.computer.relative_base.checked_add(parameter.value)?,
+        };
+        usize::try_from(address).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asm::assemble;
+
+    fn load(source: &str) -> Debugger {
+        Debugger::new(Computer::from_memory(assemble(source).unwrap()))
+    }
+
+    const SUM: &str = "
+            add #1, #2, x   ; 0
+            add x, #3, x    ; 4
+            out x           ; 8
+            hlt             ; 10
+        x:  data 0          ; 11
+    ";
+
+    #[test]
+    fn breakpoints() {
+        let mut debugger = load(SUM);
+        debugger.add_breakpoint(0);
+        debugger.add_breakpoint(8);
+        assert_eq!(debugger.resume(), Stop::Breakpoint(8));
+        assert_eq!(debugger.computer().instruction_pointer(), 8);
+        assert_eq!(debugger.resume(), Stop::Halted);
+        assert_eq!(debugger.take_outputs(), [6]);
+        assert_eq!(debugger.step(), Stop::Halted);
+    }
+
+    #[test]
+    fn opcode_breakpoints() {
+        let mut debugger = load(SUM);
+        debugger.add_opcode_breakpoint(4);
+        assert_eq!(debugger.resume(), Stop::Breakpoint(8));
+        assert!(debugger.remove_opcode_breakpoint(4));
+        assert_eq!(debugger.resume(), Stop::Halted);
+    }
+
+    #[test]
+    fn watchpoints() {
+        let mut debugger = load(SUM);
+        debugger.add_watchpoint(11);
+        assert_eq!(
+            debugger.resume(),
+            Stop::Watchpoint {
+                address: 11,
+                old: 0,
+                new: 3,
+            }
+        );
+        assert_eq!(
+            debugger.resume(),
+            Stop::Watchpoint {
+                address: 11,
+                old: 3,
+                new: 6,
+            }
+        );
+        assert_eq!(debugger.resume(), Stop::Halted);
+
+        let mut debugger = load("arb #10\nadd #5, #0, @2\nhlt");
+        debugger.add_watchpoint(12);
+        assert_eq!(
+            debugger.resume(),
+            Stop::Watchpoint {
+                address: 12,
+                old: 0,
+                new: 5,
+            }
+        );
+    }
+
+    #[test]
+    fn relative_overflow() {
+        let mut debugger = load("arb #9223372036854775807\nadd #1, #0, @1");
+        assert_eq!(
+            debugger.resume(),
+            Stop::Error(Error::AddressOverflow {
+                instruction_pointer: 2,
+                opcode: 21101,
+            })
+        );
+        assert_eq!(debugger.trace().count(), 2);
+        debugger.step();
+        assert_eq!(debugger.trace().count(), 2);
+    }
+
+    #[test]
+    fn trace() {
+        let mut debugger = load("arb #1\nadd #1, x, x\nadd #1, x, x\nadd #1, x, x\nhlt\nx: data 0");
+        debugger.set_trace_capacity(3);
+        assert_eq!(debugger.resume(), Stop::Halted);
+        let addresses = |debugger: &Debugger| {
+            debugger
+                .trace()
+                .map(|entry| entry.address)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(addresses(&debugger), [6, 10, 14]);
+        assert_eq!(debugger.trace().next().unwrap().relative_base, 1);
+        debugger.set_trace_capacity(2);
+        assert_eq!(addresses(&debugger), [10, 14]);
+        assert_eq!(
+            debugger.trace().last().unwrap().instruction,
+            Instruction::Halt
+        );
+    }
+
+    #[test]
+    fn input() {
+        let mut debugger = load("in x\nout x\nhlt\nx: data 0");
+        assert_eq!(debugger.resume(), Stop::Input);
+        assert_eq!(debugger.step(), Stop::Input);
+        assert_eq!(debugger.computer().instruction_pointer(), 0);
+        debugger.push_input(7);
+        assert_eq!(debugger.resume(), Stop::Halted);
+        assert_eq!(debugger.take_outputs(), [7]);
+    }
+}
@@ -0,0 +1,410 @@
+//! Decoding of intcode memory into readable listings.
+//!
+//! Listings use one mnemonic per opcode: `add`, `mul`, `in`, `out`, `jt`, `jf`, `lt`, `eq`, `arb` and `hlt`.
+//! Parameters in position mode are written as plain addresses, in immediate mode with a `#` prefix,
+//! and in relative mode with a `@` prefix, so `add #5, 100, @-2` adds `5` to the value at address `100`
+//! and stores the result two addresses below the relative base.
+//! Jump targets are labelled `L<address>`, and words that are not reachable as code are listed as `data`.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::{self, Write},
+};
+
+use crate::ParameterMode;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Parameter {
+    pub mode: ParameterMode,
+    pub value: i64,
+}
+
+impl fmt::Display for Parameter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.mode {
+            ParameterMode::Position => write!(f, "{}", self.value),
+            ParameterMode::Immediate => write!(f, "#{}", self.value),
+            ParameterMode::Relative => write!(f, "@{}", self.value),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Instruction {
+    Add(Parameter, Parameter, Parameter),
+    Mul(Parameter, Parameter, Parameter),
+    Input(Parameter),
+    Output(Parameter),
+    JumpIfTrue(Parameter, Parameter),
+    JumpIfFalse(Parameter, Parameter),
+    LessThan(Parameter, Parameter, Parameter),
+    Equals(Parameter, Parameter, Parameter),
+    AdjustRelativeBase(Parameter),
+    Halt,
+}
+
+impl Instruction {
+    /// Decodes the instruction starting at `address`, or returns `None` if the words there
+    /// are not a valid instruction, including if it would write to an immediate-mode parameter.
+    pub fn decode(memory: &[i64], address: usize) -> Option<Instruction> {
+        let value = *memory.get(address)?;
+        if !(0..100_000).contains(&value) {
+            return None;
+        }
+        let modes = ParameterMode::modes(value / 100)?;
+        let size = match value % 100 {
+            1 | 2 | 7 | 8 => 4,
+            3 | 4 | 9 => 2,
+            5 | 6 => 3,
+            99 => 1,
+            _ => return None,
+        };
+        let words = memory.get(address + 1..address + size)?;
+        let p = |i: usize| Parameter {
+            mode: modes[i],
+            value: words[i],
+        };
+        let instruction = match value % 100 {
+            1 => Instruction::Add(p(0), p(1), p(2)),
+            2 => Instruction::Mul(p(0), p(1), p(2)),
+            3 => Instruction::Input(p(0)),
+            4 => Instruction::Output(p(0)),
+            5 => Instruction::JumpIfTrue(p(0), p(1)),
+            6 => Instruction::JumpIfFalse(p(0), p(1)),
+            7 => Instruction::LessThan(p(0), p(1), p(2)),
+            8 => Instruction::Equals(p(0), p(1), p(2)),
+            9 => Instruction::AdjustRelativeBase(p(0)),
+            _ => Instruction::Halt,
+        };
+        let unused_modes = modes[instruction.parameters().len()..]
+            .iter()
+            .any(|&mode| mode != ParameterMode::Position);
+        if unused_modes
+            || instruction.destination().map(|p| p.mode) == Some(ParameterMode::Immediate)
+        {
+            return None;
+        }
+        Some(instruction)
+    }
+
+    pub fn opcode(&self) -> i64 {
+        match self {
+            Instruction::Add(..) => 1,
+            Instruction::Mul(..) => 2,
+            Instruction::Input(..) => 3,
+            Instruction::Output(..) => 4,
+            Instruction::JumpIfTrue(..) => 5,
+            Instruction::JumpIfFalse(..) => 6,
+            Instruction::LessThan(..) => 7,
+            Instruction::Equals(..) => 8,
+            Instruction::AdjustRelativeBase(..) => 9,
+            Instruction::Halt => 99,
+        }
+    }
+
+    pub fn mnemonic(&self) -> &'static str {
+        match self {
+            Instruction::Add(..) => "add",
+            Instruction::Mul(..) => "mul",
+            Instruction::Input(..) => "in",
+            Instruction::Output(..) => "out",
+            Instruction::JumpIfTrue(..) => "jt",
+            Instruction::JumpIfFalse(..) => "jf",
+            Instruction::LessThan(..) => "lt",
+            Instruction::Equals(..) => "eq",
+            Instruction::AdjustRelativeBase(..) => "arb",
+            Instruction::Halt => "hlt",
+        }
+    }
+
+    pub fn parameters(&self) -> Vec<Parameter> {
+        match *self {
+            Instruction::Add(a, b, c)
+            | Instruction::Mul(a, b, c)
+            | Instruction::LessThan(a, b, c)
+            | Instruction::Equals(a, b, c) => vec![a, b, c],
+            Instruction::JumpIfTrue(a, b) | Instruction::JumpIfFalse(a, b) => vec![a, b],
+            Instruction::Input(a) | Instruction::Output(a) | Instruction::AdjustRelativeBase(a) => {
+                vec![a]
+            }
+            Instruction::Halt => vec![],
+        }
+    }
+
+    /// Returns the number of words the instruction takes up in memory.
+    pub fn size(&self) -> usize {
+        1 + self.parameters().len()
+    }
+
+    /// Returns the words encoding the instruction in memory.
+    pub fn encode(&self) -> Vec<i64> {
+        let parameters = self.parameters();
+        let modes = parameters
+            .iter()
+            .rev()
+            .fold(0, |acc, p| acc * 10 + p.mode as i64);
+        let mut words = vec![modes * 100 + self.opcode()];
+        words.extend(parameters.iter().map(|p| p.value));
+        words
+    }
+
+    /// Returns the parameter the instruction writes to, if any.
+    pub fn destination(&self) -> Option<Parameter> {
+        match *self {
+            Instruction::Add(_, _, c)
+            | Instruction::Mul(_, _, c)
+            | Instruction::LessThan(_, _, c)
+            | Instruction::Equals(_, _, c) => Some(c),
+            Instruction::Input(a) => Some(a),
+            _ => None,
+        }
+    }
+
+    /// Returns the address the instruction may jump to, if it is a jump with an immediate-mode target.
+    pub fn jump_target(&self) -> Option<usize> {
+        match *self {
+            Instruction::JumpIfTrue(_, target) | Instruction::JumpIfFalse(_, target)
+                if target.mode == ParameterMode::Immediate =>
+            {
+                usize::try_from(target.value).ok()
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns whether execution may continue with the next instruction in memory.
+    pub fn falls_through(&self) -> bool {
+        match *self {
+            Instruction::JumpIfTrue(condition, _) => {
+                condition.mode != ParameterMode::Immediate || condition.value == 0
+            }
+            Instruction::JumpIfFalse(condition, _) => {
+                condition.mode != ParameterMode::Immediate || condition.value != 0
+            }
+            Instruction::Halt => false,
+            _ => true,
+        }
+    }
+
+    fn write(&self, f: &mut impl Write, labels: &BTreeSet<usize>) -> fmt::Result {
+        write!(f, "{}", self.mnemonic())?;
+        let target = self.jump_target().filter(|target| labels.contains(target));
+        for (i, parameter) in self.parameters().iter().enumerate() {
+            f.write_str(if i == 0 { " " } else { ", " })?;
+            match target {
+                Some(target) if i == 1 => write!(f, "#L{target}")?,
+                _ => write!(f, "{parameter}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, &BTreeSet::new())
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Item {
+    Instruction(usize, Instruction),
+    Data(usize, Vec<i64>),
+}
+
+impl Item {
+    pub fn address(&self) -> usize {
+        match *self {
+            Item::Instruction(address, _) | Item::Data(address, _) => address,
+        }
+    }
+}
+
+/// A disassembled program, made up of instructions and data regions in order of address.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Listing {
+    items: Vec<Item>,
+    labels: BTreeSet<usize>,
+}
+
+impl Listing {
+    pub fn items(&self) -> &[Item] {
+        &self.items
+    }
+
+    /// Returns the labelled addresses, which are the targets of jumps and returns.
+    pub fn labels(&self) -> &BTreeSet<usize> {
+        &self.labels
+    }
+}
+
+impl fmt::Display for Listing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for item in &self.items {
+            if self.labels.contains(&item.address()) {
+                writeln!(f, "L{}:", item.address())?;
+            }
+            match item {
+                Item::Instruction(address, instruction) => {
+                    let mut line = String::new();
+                    instruction.write(&mut line, &self.labels)?;
+                    writeln!(f, "    {line:<32} ; {address}")?;
+                }
+                Item::Data(address, values) => {
+                    for (i, chunk) in values.chunks(8).enumerate() {
+                        let values: Vec<_> = chunk.iter().map(i64::to_string).collect();
+                        let line = format!("data {}", values.join(", "));
+                        writeln!(f, "    {line:<32} ; {}", address + i * 8)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Disassembles `memory` by following control flow from address `0`.
+///
+/// Jumps with immediate-mode targets are followed. Jumps with other targets cannot be followed,
+/// so the word after an unconditional jump is also treated as code if its address appears as an
+/// immediate-mode parameter elsewhere, as it does when a return address is pushed before a call.
+/// Everything not reached is treated as data.
+pub fn disassemble(memory: &[i64]) -> Listing {
+    let mut code = BTreeMap::new();
+    let mut covered = vec![false; memory.len()];
+    let mut labels = BTreeSet::new();
+    let mut visited = BTreeSet::new();
+    let mut pending = vec![0];
+    loop {
+        while let Some(address) = pending.pop() {
+            if !visited.insert(address) {
+                continue;
+            }
+            let Some(instruction) = Instruction::decode(memory, address) else {
+                continue;
+            };
+            let span = address..address + instruction.size();
+            if covered[span.clone()].iter().any(|&c| c) {
+                continue;
+            }
+            covered[span.clone()].fill(true);
+            code.insert(address, instruction);
+            if let Some(target) = instruction.jump_target() {
+                if target < memory.len() {
+                    labels.insert(target);
+                    pending.push(target);
+                }
+            }
+            if instruction.falls_through() {
+                pending.push(span.end);
+            }
+        }
+        let immediates: BTreeSet<i64> = code
+            .values()
+            .flat_map(Instruction::parameters)
+            .filter(|p| p.mode == ParameterMode::Immediate)
+            .map(|p| p.value)
+            .collect();
+        let returns: Vec<usize> = code
+            .iter()
+            .filter(|(_, instruction)| !instruction.falls_through())
+            .map(|(&address, instruction)| address + instruction.size())
+            .filter(|&address| {
+                address < memory.len()
+                    && !visited.contains(&address)
+                    && immediates.contains(&(address as i64))
+            })
+            .collect();
+        if returns.is_empty() {
+            break;
+        }
+        labels.extend(&returns);
+        pending = returns;
+    }
+
+    let mut items = Vec::new();
+    let mut address = 0;
+    while address < memory.len() {
+        if let Some(&instruction) = code.get(&address) {
+            items.push(Item::Instruction(address, instruction));
+            address += instruction.size();
+        } else {
+            let start = address;
+            address += 1;
+            while address < memory.len()
+                && !code.contains_key(&address)
+                && !labels.contains(&address)
+            {
+                address += 1;
+            }
+            items.push(Item::Data(start, memory[start..address].to_vec()));
+        }
+    }
+    // jumps into the middle of an instruction are left unlabelled
+    labels.retain(|address| code.contains_key(address) || !covered[*address]);
+    Listing { items, labels }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asm::assemble;
+
+    #[test]
+    fn round_trip() {
+        let memory = assemble(
+            "
+                in n
+                add #ret, #0, return
+                jt #1, #double
+            ret:
+                out n
+                hlt
+            double:
+                mul n, #2, n
+                jt #1, return
+            n:  data 0
+            return: data 0, -7, 123456
+            ",
+        )
+        .unwrap();
+        let listing = disassemble(&memory);
+        assert_eq!(listing.labels(), &BTreeSet::from([9, 12]));
+        assert_eq!(
+            listing.items().last(),
+            Some(&Item::Data(19, vec![0, 0, -7, 123456]))
+        );
+        assert_eq!(assemble(&listing.to_string()).unwrap(), memory);
+    }
+
+    #[test]
+    fn round_trip_extremes() {
+        let memory = vec![1101, i64::MIN, i64::MAX, 7, 99, i64::MIN, i64::MAX, 0];
+        let listing = disassemble(&memory);
+        assert_eq!(assemble(&listing.to_string()).unwrap(), memory);
+    }
+
+    #[test]
+    fn decode() {
+        assert_eq!(
+            Instruction::decode(&[1201, 4, 5, 6], 0),
+            Some(Instruction::Add(
+                Parameter {
+                    mode: ParameterMode::Relative,
+                    value: 4
+                },
+                Parameter {
+                    mode: ParameterMode::Immediate,
+                    value: 5
+                },
+                Parameter {
+                    mode: ParameterMode::Position,
+                    value: 6
+                },
+            ))
+        );
+        assert_eq!(Instruction::decode(&[11101, 4, 5, 6], 0), None);
+        assert_eq!(Instruction::decode(&[104], 0), None);
+        assert_eq!(Instruction::decode(&[1099], 0), None);
+    }
+}
@@ -1,3 +1,12 @@
+pub mod asm;
+pub mod debug;
+pub mod disasm;
+
+use std::{
+    collections::{vec_deque, VecDeque},
+    fmt,
+};
+
 #[derive(Clone)]
 pub struct Computer {
     memory: Vec<i64>,
@@ -5,37 +14,101 @@ pub struct Computer {
     relative_base: i64,
     parameter_modes: [ParameterMode; 3],
     state: State,
+    fault: Option<Error>,
+    inputs: VecDeque<i64>,
+    outputs: VecDeque<i64>,
 }
 
 impl Computer {
-    pub fn new(input: &str) -> Option<Computer> {
-        let memory: Option<Vec<i64>> = input.split(',').map(|n| n.parse().ok()).collect();
-        Some(Computer {
-            memory: memory?,
+    pub fn new(input: &str) -> Result<Computer, ParseError> {
+        let memory = input
+            .split(',')
+            .enumerate()
+            .map(|(position, token)| {
+                token.trim().parse().map_err(|_| ParseError {
+                    position,
+                    token: token.to_string(),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Computer::from_memory(memory))
+    }
+
+    pub fn from_memory(memory: Vec<i64>) -> Computer {
+        Computer {
+            memory,
             instruction_pointer: 0,
             relative_base: 0,
             parameter_modes: [ParameterMode::Position; 3],
             state: State::Running,
-        })
+            fault: None,
+            inputs: VecDeque::new(),
+            outputs: VecDeque::new(),
+        }
+    }
+
+    pub fn run(&mut self) -> Result<(), Error> {
+        loop {
+            self.step()?;
+            if self.state != State::Running {
+                return Ok(());
+            }
+        }
     }
 
-    pub fn run(&mut self) {
-        while self.state == State::Running {
-            if let Some(modes) =
-                ParameterMode::modes(self.get_direct(self.instruction_pointer) / 100)
-            {
-                self.parameter_modes = modes;
-                if self.run_instruction().is_none() {
-                    self.state = State::Error;
+    /// Runs the program, taking input from the input queue and adding output to the output queue,
+    /// until it needs input and the queue is empty, or it halts.
+    pub fn run_until_blocked(&mut self) -> Result<Blocked, Error> {
+        loop {
+            self.run()?;
+            match self.state {
+                State::Input => match self.inputs.pop_front() {
+                    Some(value) => self.provide_input(value)?,
+                    None => return Ok(Blocked::Input),
+                },
+                State::Output => {
+                    let value = self.take_output()?;
+                    self.outputs.push_back(value);
                 }
-            } else {
-                self.state = State::Error;
+                State::Halted => return Ok(Blocked::Halted),
+                State::Running => {}
             }
         }
     }
 
-    fn run_instruction(&mut self) -> Option<()> {
-        match self.get_direct(self.instruction_pointer) % 100 {
+    /// Adds values to the input queue read by [`run_until_blocked`](Computer::run_until_blocked).
+    pub fn push_inputs(&mut self, values: impl IntoIterator<Item = i64>) {
+        self.inputs.extend(values);
+    }
+
+    /// Removes and returns every value in the output queue written by
+    /// [`run_until_blocked`](Computer::run_until_blocked).
+    pub fn drain_outputs(&mut self) -> vec_deque::Drain<'_, i64> {
+        self.outputs.drain(..)
+    }
+
+    pub fn step(&mut self) -> Result<(), Error> {
+        self.guard(|computer| {
+            if computer.state != State::Running {
+                return Ok(());
+            }
+            if computer.opcode() < 0 {
+                return Err(Error::UnknownOpcode {
+                    instruction_pointer: computer.instruction_pointer,
+                    opcode: computer.opcode(),
+                });
+            }
+            computer.parameter_modes =
+                ParameterMode::modes(computer.opcode() / 100).ok_or(Error::InvalidMode {
+                    instruction_pointer: computer.instruction_pointer,
+                    opcode: computer.opcode(),
+                })?;
+            computer.run_instruction()
+        })
+    }
+
+    fn run_instruction(&mut self) -> Result<(), Error> {
+        match self.opcode() % 100 {
             1 => {
                 self.set(3, self.get(1)? + self.get(2)?)?;
                 self.instruction_pointer += 4;
@@ -48,24 +121,14 @@ impl Computer {
             4 => self.state = State::Output,
             5 => {
                 if self.get(1)? != 0 {
-                    let new = self.get(2)?;
-                    if new >= 0 {
-                        self.instruction_pointer = new as usize;
-                    } else {
-                        return None;
-                    }
+                    self.jump(self.get(2)?)?;
                 } else {
                     self.instruction_pointer += 3;
                 }
             }
             6 => {
                 if self.get(1)? == 0 {
-                    let new = self.get(2)?;
-                    if new >= 0 {
-                        self.instruction_pointer = new as usize;
-                    } else {
-                        return None;
-                    }
+                    self.jump(self.get(2)?)?;
                 } else {
                     self.instruction_pointer += 3;
                 }
@@ -87,76 +150,135 @@ impl Computer {
                 self.instruction_pointer += 4;
             }
             9 => {
-                self.relative_base += self.get(1)?;
+                self.relative_base =
+                    self.relative_base
+                        .checked_add(self.get(1)?)
+                        .ok_or(Error::AddressOverflow {
+                            instruction_pointer: self.instruction_pointer,
+                            opcode: self.opcode(),
+                        })?;
                 self.instruction_pointer += 2;
             }
             99 => self.state = State::Halted,
-            _ => self.state = State::Error,
+            _ => {
+                return Err(Error::UnknownOpcode {
+                    instruction_pointer: self.instruction_pointer,
+                    opcode: self.opcode(),
+                })
+            }
         };
-        Some(())
+        Ok(())
     }
 
-    pub fn input(&mut self, value: i64) {
-        if self.state == State::Input {
-            if self.set(1, value).is_some() {
-                self.instruction_pointer += 2;
-                self.state = State::Running;
-                self.run();
-            } else {
-                self.state = State::Error;
-            }
+    pub fn input(&mut self, value: i64) -> Result<(), Error> {
+        if let Some(error) = self.fault {
+            return Err(error);
         }
+        if self.state != State::Input {
+            return Err(Error::NotWaitingForInput {
+                instruction_pointer: self.instruction_pointer,
+                opcode: self.opcode(),
+            });
+        }
+        self.provide_input(value)?;
+        self.run()
     }
 
-    pub fn output(&mut self) -> Option<i64> {
-        if self.state == State::Output {
-            if let Some(result) = self.get(1) {
-                self.instruction_pointer += 2;
-                self.state = State::Running;
-                self.run();
-                return Some(result);
-            } else {
-                self.state = State::Error;
-            }
+    pub fn output(&mut self) -> Result<i64, Error> {
+        if let Some(error) = self.fault {
+            return Err(error);
+        }
+        if self.state != State::Output {
+            return Err(Error::NotOutputting {
+                instruction_pointer: self.instruction_pointer,
+                opcode: self.opcode(),
+            });
         }
-        None
+        let result = self.take_output()?;
+        self.run()?;
+        Ok(result)
+    }
+
+    fn provide_input(&mut self, value: i64) -> Result<(), Error> {
+        self.guard(|computer| {
+            computer.set(1, value)?;
+            computer.instruction_pointer += 2;
+            computer.state = State::Running;
+            Ok(())
+        })
+    }
+
+    fn take_output(&mut self) -> Result<i64, Error> {
+        self.guard(|computer| {
+            let result = computer.get(1)?;
+            computer.instruction_pointer += 2;
+            computer.state = State::Running;
+            Ok(result)
+        })
     }
 
-    fn get(&self, parameter: usize) -> Option<i64> {
+    fn guard<T>(&mut self, f: impl FnOnce(&mut Computer) -> Result<T, Error>) -> Result<T, Error> {
+        if let Some(error) = self.fault {
+            return Err(error);
+        }
+        let result = f(self);
+        if let Err(error) = result {
+            self.fault = Some(error);
+        }
+        result
+    }
+
+    fn jump(&mut self, target: i64) -> Result<(), Error> {
+        self.instruction_pointer = self.address(target)?;
+        Ok(())
+    }
+
+    fn get(&self, parameter: usize) -> Result<i64, Error> {
         let value = self.get_direct(self.instruction_pointer + parameter);
-        Some(match self.parameter_modes[parameter - 1] {
-            ParameterMode::Position => {
-                if value >= 0 {
-                    self.get_direct(value as usize)
-                } else {
-                    return None;
-                }
-            }
+        Ok(match self.parameter_modes[parameter - 1] {
+            ParameterMode::Position => self.get_direct(self.address(value)?),
             ParameterMode::Immediate => value,
-            ParameterMode::Relative => {
-                if value + self.relative_base >= 0 {
-                    self.get_direct((value + self.relative_base) as usize)
-                } else {
-                    return None;
-                }
+            ParameterMode::Relative => self.get_direct(self.relative_address(value)?),
+        })
+    }
+
+    fn set(&mut self, parameter: usize, value: i64) -> Result<(), Error> {
+        let offset = self.get_direct(self.instruction_pointer + parameter);
+        let address = match self.parameter_modes[parameter - 1] {
+            ParameterMode::Position => self.address(offset)?,
+            ParameterMode::Immediate => {
+                return Err(Error::ImmediateWrite {
+                    instruction_pointer: self.instruction_pointer,
+                    opcode: self.opcode(),
+                });
             }
+            ParameterMode::Relative => self.relative_address(offset)?,
+        };
+        self.set_direct(address, value);
+        Ok(())
+    }
+
+    fn address(&self, address: i64) -> Result<usize, Error> {
+        usize::try_from(address).map_err(|_| Error::NegativeAddress {
+            instruction_pointer: self.instruction_pointer,
+            opcode: self.opcode(),
+            address,
         })
     }
 
-    fn set(&mut self, parameter: usize, value: i64) -> Option<()> {
-        let address = self.get_direct(self.instruction_pointer + parameter)
-            + match self.parameter_modes[parameter - 1] {
-                ParameterMode::Position => 0,
-                ParameterMode::Immediate => {
-                    return None;
-                }
-                ParameterMode::Relative => self.relative_base,
-            };
-        if address < 0 {
-            return None;
-        }
-        self.set_direct(address as usize, value);
-        Some(())
+    fn relative_address(&self, offset: i64) -> Result<usize, Error> {
+        let address = self
+            .relative_base
+            .checked_add(offset)
+            .ok_or(Error::AddressOverflow {
+                instruction_pointer: self.instruction_pointer,
+                opcode: self.opcode(),
+            })?;
+        self.address(address)
+    }
+
+    fn opcode(&self) -> i64 {
+        self.get_direct(self.instruction_pointer)
     }
 
     pub fn get_direct(&self, address: usize) -> i64 {
@@ -167,6 +289,10 @@ impl Computer {
         }
     }
 
+    pub fn memory(&self) -> &[i64] {
+        &self.memory
+    }
+
     pub fn set_direct(&mut self, address: usize, value: i64) {
         while self.memory.len() <= address {
             self.memory.push(0);
@@ -177,6 +303,22 @@ impl Computer {
     pub fn state(&self) -> State {
         self.state
     }
+
+    /// Returns the error that stopped the program, if any.
+    ///
+    /// Once an instruction fails, the computer executes nothing further,
+    /// and every call that would run it returns the same error.
+    pub fn fault(&self) -> Option<Error> {
+        self.fault
+    }
+
+    pub fn instruction_pointer(&self) -> usize {
+        self.instruction_pointer
+    }
+
+    pub fn relative_base(&self) -> i64 {
+        self.relative_base
+    }
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
@@ -185,11 +327,136 @@ pub enum State {
     Input,
     Output,
     Halted,
-    Error,
 }
 
-#[derive(Clone, Copy, PartialEq, Eq)]
-enum ParameterMode {
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Blocked {
+    Input,
+    Halted,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Error {
+    UnknownOpcode {
+        instruction_pointer: usize,
+        opcode: i64,
+    },
+    InvalidMode {
+        instruction_pointer: usize,
+        opcode: i64,
+    },
+    NegativeAddress {
+        instruction_pointer: usize,
+        opcode: i64,
+        address: i64,
+    },
+    AddressOverflow {
+        instruction_pointer: usize,
+        opcode: i64,
+    },
+    ImmediateWrite {
+        instruction_pointer: usize,
+        opcode: i64,
+    },
+    NotWaitingForInput {
+        instruction_pointer: usize,
+        opcode: i64,
+    },
+    NotOutputting {
+        instruction_pointer: usize,
+        opcode: i64,
+    },
+}
+
+impl Error {
+    pub fn instruction_pointer(&self) -> usize {
+        match *self {
+            Error::UnknownOpcode {
+                instruction_pointer,
+                ..
+            }
+            | Error::InvalidMode {
+                instruction_pointer,
+                ..
+            }
+            | Error::NegativeAddress {
+                instruction_pointer,
+                ..
+            }
+            | Error::AddressOverflow {
+                instruction_pointer,
+                ..
+            }
+            | Error::ImmediateWrite {
+                instruction_pointer,
+                ..
+            }
+            | Error::NotWaitingForInput {
+                instruction_pointer,
+                ..
+            }
+            | Error::NotOutputting {
+                instruction_pointer,
+                ..
+            } => instruction_pointer,
+        }
+    }
+
+    pub fn opcode(&self) -> i64 {
+        match *self {
+            Error::UnknownOpcode { opcode, .. }
+            | Error::InvalidMode { opcode, .. }
+            | Error::NegativeAddress { opcode, .. }
+            | Error::AddressOverflow { opcode, .. }
+            | Error::ImmediateWrite { opcode, .. }
+            | Error::NotWaitingForInput { opcode, .. }
+            | Error::NotOutputting { opcode, .. } => opcode,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Error::UnknownOpcode { .. } => write!(f, "unknown opcode")?,
+            Error::InvalidMode { .. } => write!(f, "invalid parameter mode")?,
+            Error::NegativeAddress { address, .. } => write!(f, "negative address {address}")?,
+            Error::AddressOverflow { .. } => write!(f, "relative address overflow")?,
+            Error::ImmediateWrite { .. } => write!(f, "write to parameter in immediate mode")?,
+            Error::NotWaitingForInput { .. } => write!(f, "input when not waiting for input")?,
+            Error::NotOutputting { .. } => write!(f, "output when not outputting")?,
+        }
+        write!(
+            f,
+            " at instruction pointer {} with opcode {}",
+            self.instruction_pointer(),
+            self.opcode()
+        )
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParseError {
+    pub position: usize,
+    pub token: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid token `{}` at position {}",
+            self.token, self.position
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ParameterMode {
     Position,
     Immediate,
     Relative,
@@ -213,3 +480,187 @@ impl ParameterMode {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asm::assemble;
+
+    fn run(memory: &[i64]) -> Result<(), Error> {
+        Computer::from_memory(memory.to_vec()).run()
+    }
+
+    #[test]
+    fn unknown_opcode() {
+        assert_eq!(
+            run(&[1, 0, 0, 0, 42]),
+            Err(Error::UnknownOpcode {
+                instruction_pointer: 4,
+                opcode: 42,
+            })
+        );
+        assert_eq!(
+            run(&[-1]),
+            Err(Error::UnknownOpcode {
+                instruction_pointer: 0,
+                opcode: -1,
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_mode() {
+        assert_eq!(
+            run(&[301, 0, 0, 0]),
+            Err(Error::InvalidMode {
+                instruction_pointer: 0,
+                opcode: 301,
+            })
+        );
+    }
+
+    #[test]
+    fn negative_address() {
+        assert_eq!(
+            run(&[1, -1, 0, 0]),
+            Err(Error::NegativeAddress {
+                instruction_pointer: 0,
+                opcode: 1,
+                address: -1,
+            })
+        );
+        assert_eq!(
+            Computer::from_memory(vec![109, -3, 204, 1]).run_until_blocked(),
+            Err(Error::NegativeAddress {
+                instruction_pointer: 2,
+                opcode: 204,
+                address: -2,
+            })
+        );
+    }
+
+    #[test]
+    fn address_overflow() {
+        assert_eq!(
+            run(&[109, i64::MAX, 22201, 1, 2, 3]),
+            Err(Error::AddressOverflow {
+                instruction_pointer: 2,
+                opcode: 22201,
+            })
+        );
+        assert_eq!(
+            run(&[109, i64::MAX, 109, 1]),
+            Err(Error::AddressOverflow {
+                instruction_pointer: 2,
+                opcode: 109,
+            })
+        );
+    }
+
+    #[test]
+    fn immediate_write() {
+        assert_eq!(
+            run(&[11101, 1, 1, 0]),
+            Err(Error::ImmediateWrite {
+                instruction_pointer: 0,
+                opcode: 11101,
+            })
+        );
+    }
+
+    #[test]
+    fn not_waiting_for_input() {
+        let mut computer = Computer::from_memory(vec![99]);
+        computer.run().unwrap();
+        assert_eq!(
+            computer.input(1),
+            Err(Error::NotWaitingForInput {
+                instruction_pointer: 0,
+                opcode: 99,
+            })
+        );
+        assert_eq!(computer.fault(), None);
+    }
+
+    #[test]
+    fn not_outputting() {
+        let mut computer = Computer::from_memory(vec![3, 0, 99]);
+        computer.run().unwrap();
+        assert_eq!(
+            computer.output(),
+            Err(Error::NotOutputting {
+                instruction_pointer: 0,
+                opcode: 3,
+            })
+        );
+        computer.input(5).unwrap();
+        assert_eq!(computer.state(), State::Halted);
+    }
+
+    #[test]
+    fn fault_persists() {
+        let mut computer = Computer::from_memory(vec![103, 0, 99]);
+        computer.run().unwrap();
+        let error = Error::ImmediateWrite {
+            instruction_pointer: 0,
+            opcode: 103,
+        };
+        assert_eq!(computer.input(1), Err(error));
+        assert_eq!(computer.fault(), Some(error));
+        assert_eq!(computer.input(1), Err(error));
+        assert_eq!(computer.run(), Err(error));
+        assert_eq!(computer.step(), Err(error));
+        assert_eq!(computer.run_until_blocked(), Err(error));
+        assert_eq!(computer.instruction_pointer(), 0);
+    }
+
+    fn load(source: &str) -> Computer {
+        Computer::from_memory(assemble(source).unwrap())
+    }
+
+    #[test]
+    fn blocked_on_input() {
+        let mut computer = load(
+            "
+            loop:
+                in x
+                mul x, #2, x
+                out x
+                jt #1, #loop
+            x:  data 0
+            ",
+        );
+        assert_eq!(computer.run_until_blocked(), Ok(Blocked::Input));
+        assert_eq!(computer.drain_outputs().len(), 0);
+        computer.push_inputs([1, 2]);
+        computer.push_inputs([3]);
+        assert_eq!(computer.run_until_blocked(), Ok(Blocked::Input));
+        assert!(computer.drain_outputs().eq([2, 4, 6]));
+        assert_eq!(computer.state(), State::Input);
+    }
+
+    #[test]
+    fn blocked_on_halt() {
+        let mut computer = load("out #1\nout #2\nout #3\nhlt");
+        assert_eq!(computer.run_until_blocked(), Ok(Blocked::Halted));
+        assert!(computer.drain_outputs().eq([1, 2, 3]));
+        assert_eq!(computer.run_until_blocked(), Ok(Blocked::Halted));
+        assert_eq!(computer.drain_outputs().len(), 0);
+    }
+
+    #[test]
+    fn blocked_on_error() {
+        let mut computer = load("in x\nout x\ndata 42\nx: data 0");
+        computer.push_inputs([5]);
+        let error = Error::UnknownOpcode {
+            instruction_pointer: 4,
+            opcode: 42,
+        };
+        assert_eq!(computer.run_until_blocked(), Err(error));
+        assert!(computer.drain_outputs().eq([5]));
+        computer.push_inputs([6]);
+        assert_eq!(computer.run_until_blocked(), Err(error));
+        assert_eq!(computer.drain_outputs().len(), 0);
+        assert_eq!(computer.instruction_pointer(), 4);
+    }
+}
@@ -2,7 +2,7 @@ use std::ops::Sub;
 
 use aoc::{IterUnwrap, Parse};
 use nd::{v, v3, Matrix, Vec2, Vec3};
-use num::{BigRational, FromPrimitive, ToPrimitive};
+use num::{BigInt, ToPrimitive};
 
 aoc::parts!(1, 2);
 
@@ -35,7 +35,7 @@ fn part_2(input: aoc::Input) -> i64 {
             aa.x - dd.x,
             aa.y - dd.y,
         ]
-        .map(|e| BigRational::from_i64(e).unwrap()),
+        .map(BigInt::from),
     );
     let mat = Matrix::new(
         [
@@ -46,9 +46,9 @@ fn part_2(input: aoc::Input) -> i64 {
             [0, ad.vel.z, -ad.vel.y, 0, -ad.pos.z, ad.pos.y],
             [-ad.vel.z, 0, ad.vel.x, ad.pos.z, 0, -ad.pos.x],
         ]
-        .map(|r| r.map(|e| BigRational::from_i64(e).unwrap())),
+        .map(|r| r.map(BigInt::from)),
     );
-    mat.solve(rhs)
+    mat.solve_int(rhs)
         .unwrap()
         .into_iter_all()
         .map(|e| e.to_i64().unwrap())
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use nd::{v, Matrix, Vec2};
+use nd::{v, Dir4, Matrix, Vec2};
 
 type Vector = Vec2<i32>;
 
@@ -98,11 +98,9 @@ fn part_2(input: aoc::Input) -> impl ToString {
 
     let mut total = 0;
 
-    let dirs = [Vector::w(), Vector::n(), Vector::e(), Vector::s()];
-
     for (obstr, dir) in candidates {
         let start = obstr - dir;
-        let start_dir = dirs.iter().position(|d| *d == dir).unwrap();
+        let start_dir = Dir4::try_from(dir).unwrap().turn_right();
 
         let mut tortoise = start;
         let mut hare = start;
@@ -110,22 +108,27 @@ fn part_2(input: aoc::Input) -> impl ToString {
         let mut hare_dir = start_dir;
 
         loop {
-            if let Some(dest) = apply_obstr(hare, hare_dir, dests[hare_dir][hare], obstr) {
+            if let Some(dest) = apply_obstr(hare, hare_dir, dests[hare_dir.index()][hare], obstr) {
                 hare = dest;
             } else {
                 break;
             }
-            hare_dir = (hare_dir + 1) & 0b11;
-            if let Some(dest) = apply_obstr(hare, hare_dir, dests[hare_dir][hare], obstr) {
+            hare_dir = hare_dir.turn_right();
+            if let Some(dest) = apply_obstr(hare, hare_dir, dests[hare_dir.index()][hare], obstr) {
                 hare = dest;
             } else {
                 break;
             }
-            hare_dir = (hare_dir + 1) & 0b11;
+            hare_dir = hare_dir.turn_right();
 
-            tortoise =
-                apply_obstr(tortoise, tortoise_dir, dests[tortoise_dir][tortoise], obstr).unwrap();
-            tortoise_dir = (tortoise_dir + 1) & 0b11;
+            tortoise = apply_obstr(
+                tortoise,
+                tortoise_dir,
+                dests[tortoise_dir.index()][tortoise],
+                obstr,
+            )
+            .unwrap();
+            tortoise_dir = tortoise_dir.turn_right();
 
             if tortoise == hare && tortoise_dir == hare_dir {
                 total += 1;
@@ -137,24 +140,24 @@ fn part_2(input: aoc::Input) -> impl ToString {
     total
 }
 
-fn apply_obstr(start: Vector, dir: usize, end: Option<Vector>, obstr: Vector) -> Option<Vector> {
+fn apply_obstr(start: Vector, dir: Dir4, end: Option<Vector>, obstr: Vector) -> Option<Vector> {
     match dir {
-        0 => {
+        Dir4::N => {
             if obstr.x == start.x && obstr.y < start.y && end.is_none_or(|end| obstr.y >= end.y) {
                 return Some(v(obstr.x, obstr.y + 1));
             }
         }
-        1 => {
+        Dir4::E => {
             if obstr.y == start.y && obstr.x > start.x && end.is_none_or(|end| obstr.x <= end.x) {
                 return Some(v(obstr.x - 1, obstr.y));
             }
         }
-        2 => {
+        Dir4::S => {
             if obstr.x == start.x && obstr.y > start.y && end.is_none_or(|end| obstr.y <= end.y) {
                 return Some(v(obstr.x, obstr.y - 1));
             }
         }
-        _ => {
+        Dir4::W => {
             if obstr.y == start.y && obstr.x < start.x && end.is_none_or(|end| obstr.x >= end.x) {
                 return Some(v(obstr.x + 1, obstr.y));
             }
@@ -0,0 +1,207 @@
+use std::{
+    fmt,
+    ops::{Add, Mul, Sub},
+};
+
+use num_traits::{One, Zero};
+
+use crate::{interval::Interval, traits::Coords};
+
+/// An axis-aligned box of positions from `min` up to but not including `max` along each axis,
+/// which is empty if `min >= max` along any axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Aabb<V> {
+    pub min: V,
+    pub max: V,
+}
+
+impl<V> Aabb<V> {
+    /// Creates a new box containing the positions from `min` up to but not including `max` along each axis.
+    #[inline(always)]
+    pub const fn new(min: V, max: V) -> Self {
+        Self { min, max }
+    }
+}
+
+impl<V: Coords> Aabb<V>
+where
+    V::Scalar: One + Add<Output = V::Scalar> + Clone,
+{
+    /// Creates a new box containing the positions from `first` up to and including `last` along each axis.
+    pub fn from_inclusive(first: V, mut last: V) -> Self {
+        for axis in 0..V::DIM {
+            let end = last.axis(axis).clone() + V::Scalar::one();
+            *last.axis_mut(axis) = end;
+        }
+        Self::new(first, last)
+    }
+}
+
+impl<V: Coords> Aabb<V>
+where
+    V::Scalar: Ord + Clone,
+{
+    /// Returns the range of the box along `axis`.
+    ///
+    /// Panics if `axis` is out of bounds.
+    #[track_caller]
+    pub fn interval(&self, axis: usize) -> Interval<V::Scalar> {
+        Interval::new(self.min.axis(axis).clone(), self.max.axis(axis).clone())
+    }
+
+    /// Returns whether the box contains no positions.
+    pub fn is_empty(&self) -> bool {
+        (0..V::DIM).any(|axis| self.interval(axis).is_empty())
+    }
+
+    /// Returns whether `pos` is in the box.
+    pub fn contains(&self, pos: &V) -> bool {
+        (0..V::DIM).all(|axis| self.interval(axis).contains(pos.axis(axis)))
+    }
+
+    /// Returns whether every position in `other` is in `self`.
+    pub fn covers(&self, other: &Self) -> bool {
+        other.is_empty()
+            || (0..V::DIM).all(|axis| self.interval(axis).covers(&other.interval(axis)))
+    }
+
+    /// Returns whether `self` and `other` have any positions in common.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        (0..V::DIM).all(|axis| self.interval(axis).overlaps(&other.interval(axis)))
+    }
+
+    /// Returns the box of positions in both `self` and `other`, or `None` if there are none.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let mut intersection = self.clone();
+        for axis in 0..V::DIM {
+            let interval = self.interval(axis).intersection(&other.interval(axis))?;
+            intersection.set_interval(axis, interval);
+        }
+        Some(intersection)
+    }
+
+    /// Returns the smallest box containing every position in `self` and `other`.
+    pub fn hull(&self, other: &Self) -> Self {
+        if self.is_empty() {
+            return other.clone();
+        }
+        if other.is_empty() {
+            return self.clone();
+        }
+        let mut hull = self.clone();
+        for axis in 0..V::DIM {
+            hull.set_interval(axis, self.interval(axis).hull(&other.interval(axis)));
+        }
+        hull
+    }
+
+    /// Returns the positions in either `self` or `other` as disjoint, non-empty boxes.
+    pub fn union(&self, other: &Self) -> Vec<Self> {
+        let mut pieces = other.difference(self);
+        if !self.is_empty() {
+            pieces.insert(0, self.clone());
+        }
+        pieces
+    }
+
+    /// Returns the positions in `self` but not in `other` as disjoint, non-empty boxes.
+    ///
+    /// At most `2 * DIM` boxes are returned.
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        if self.is_empty() {
+            return vec![];
+        }
+        if !self.overlaps(other) {
+            return vec![self.clone()];
+        }
+        let mut pieces = Vec::new();
+        let mut rest = self.clone();
+        for axis in 0..V::DIM {
+            let interval = rest.interval(axis);
+            let cut = other.interval(axis);
+            for piece in interval.difference(&cut) {
+                let mut piece_box = rest.clone();
+                piece_box.set_interval(axis, piece);
+                pieces.push(piece_box);
+            }
+            rest.set_interval(axis, interval.intersection(&cut).unwrap());
+        }
+        pieces
+    }
+
+    fn set_interval(&mut self, axis: usize, interval: Interval<V::Scalar>) {
+        *self.min.axis_mut(axis) = interval.start;
+        *self.max.axis_mut(axis) = interval.end;
+    }
+}
+
+impl<V: Coords> Aabb<V>
+where
+    V::Scalar: Ord + Clone + Zero + One + Sub<Output = V::Scalar> + Mul<Output = V::Scalar>,
+{
+    /// Returns the number of positions in the box, or `0` if it is empty.
+    pub fn volume(&self) -> V::Scalar {
+        (0..V::DIM).fold(V::Scalar::one(), |acc, axis| {
+            acc * self.interval(axis).len()
+        })
+    }
+}
+
+impl<V: fmt::Display> fmt::Display for Aabb<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {})", self.min, self.max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector::{v3, Vec3};
+
+    fn boxes() -> Vec<Aabb<Vec3<i32>>> {
+        let mut seed = 7u32;
+        let mut next = |n: u32| {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            (seed >> 16) % n
+        };
+        (0..60)
+            .map(|_| {
+                let min = v3(next(6) as i32, next(6) as i32, next(6) as i32);
+                let size = v3(next(5) as i32, next(5) as i32, next(5) as i32);
+                Aabb::new(min, min + size)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn difference() {
+        let boxes = boxes();
+        for a in &boxes {
+            for b in &boxes {
+                let pieces = a.difference(b);
+                assert!(pieces.len() <= 6);
+                let overlap = a.intersection(b).map_or(0, |i| i.volume());
+                let total: i32 = pieces.iter().map(Aabb::volume).sum();
+                assert_eq!(total, a.volume() - overlap);
+                for (i, piece) in pieces.iter().enumerate() {
+                    assert!(!piece.is_empty());
+                    assert!(a.covers(piece));
+                    assert!(!piece.overlaps(b));
+                    assert!(pieces[i + 1..].iter().all(|other| !piece.overlaps(other)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn difference_of_disjoint_and_covering() {
+        let a = Aabb::new(v3(0, 0, 0), v3(2, 3, 4));
+        assert_eq!(a.difference(&Aabb::new(v3(5, 0, 0), v3(6, 1, 1))), [a]);
+        assert!(a
+            .difference(&Aabb::new(v3(-1, -1, -1), v3(9, 9, 9)))
+            .is_empty());
+        assert!(Aabb::new(v3(0, 0, 0), v3(0, 1, 1))
+            .difference(&a)
+            .is_empty());
+    }
+}
@@ -0,0 +1,380 @@
+use std::{fmt, ops::Neg, str::FromStr};
+
+use num_traits::Signed;
+
+use crate::vector::Vec2;
+
+/// A change of heading by a quarter or half turn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+    Reverse,
+}
+
+impl Turn {
+    /// Returns the turn that undoes `self`.
+    #[inline]
+    pub fn inverse(self) -> Self {
+        match self {
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+            Self::Reverse => Self::Reverse,
+        }
+    }
+}
+
+impl Neg for Turn {
+    type Output = Self;
+
+    /// Returns the turn that undoes `self`.
+    #[inline]
+    fn neg(self) -> Self::Output {
+        self.inverse()
+    }
+}
+
+impl TryFrom<u8> for Turn {
+    type Error = ();
+
+    /// Parses `L`, `R` or `B` (back) into a turn.
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            b'L' => Ok(Self::Left),
+            b'R' => Ok(Self::Right),
+            b'B' => Ok(Self::Reverse),
+            _ => Err(()),
+        }
+    }
+}
+
+impl TryFrom<char> for Turn {
+    type Error = ();
+
+    /// Parses `L`, `R` or `B` (back) into a turn.
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        u8::try_from(value).map_err(|_| ())?.try_into()
+    }
+}
+
+/// One of the four orthogonal compass directions, where north is negative `y` as in [`Vec2::n`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir4 {
+    N,
+    E,
+    S,
+    W,
+}
+
+impl Dir4 {
+    /// All four directions in clockwise order, starting from north.
+    pub const ALL: [Self; 4] = [Self::N, Self::E, Self::S, Self::W];
+
+    /// Returns the position of `self` in [`Dir4::ALL`], for use in indexing arrays.
+    #[inline(always)]
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the direction at position `index` in [`Dir4::ALL`], wrapping around.
+    #[inline]
+    pub const fn from_index(index: usize) -> Self {
+        Self::ALL[index % 4]
+    }
+
+    /// Returns the direction a quarter turn anticlockwise from `self`.
+    #[inline]
+    pub const fn turn_left(self) -> Self {
+        Self::from_index(self.index() + 3)
+    }
+
+    /// Returns the direction a quarter turn clockwise from `self`.
+    #[inline]
+    pub const fn turn_right(self) -> Self {
+        Self::from_index(self.index() + 1)
+    }
+
+    /// Returns the direction opposite `self`.
+    #[inline]
+    pub const fn opposite(self) -> Self {
+        Self::from_index(self.index() + 2)
+    }
+
+    /// Returns `self` turned in the given direction.
+    #[inline]
+    pub const fn turn(self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+            Turn::Reverse => self.opposite(),
+        }
+    }
+
+    /// Returns the unit vector pointing in direction `self`.
+    #[inline]
+    pub fn to_vec<T: Signed>(self) -> Vec2<T> {
+        match self {
+            Self::N => Vec2::n(),
+            Self::E => Vec2::e(),
+            Self::S => Vec2::s(),
+            Self::W => Vec2::w(),
+        }
+    }
+
+    /// Returns the arrow character pointing in direction `self`, one of `^`, `>`, `v` or `<`.
+    #[inline]
+    pub const fn arrow(self) -> char {
+        match self {
+            Self::N => '^',
+            Self::E => '>',
+            Self::S => 'v',
+            Self::W => '<',
+        }
+    }
+}
+
+impl<T: Signed> From<Dir4> for Vec2<T> {
+    #[inline]
+    fn from(value: Dir4) -> Self {
+        value.to_vec()
+    }
+}
+
+impl<T: Signed + PartialEq> TryFrom<Vec2<T>> for Dir4 {
+    type Error = ();
+
+    /// Converts a unit vector into the direction it points in.
+    fn try_from(value: Vec2<T>) -> Result<Self, Self::Error> {
+        Self::ALL
+            .into_iter()
+            .find(|dir| dir.to_vec::<T>() == value)
+            .ok_or(())
+    }
+}
+
+impl TryFrom<u8> for Dir4 {
+    type Error = ();
+
+    /// Parses `U`, `R`, `D` or `L`, `N`, `E`, `S` or `W`, or `^`, `>`, `v` or `<` into a direction.
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            b'U' | b'N' | b'^' => Ok(Self::N),
+            b'R' | b'E' | b'>' => Ok(Self::E),
+            b'D' | b'S' | b'v' => Ok(Self::S),
+            b'L' | b'W' | b'<' => Ok(Self::W),
+            _ => Err(()),
+        }
+    }
+}
+
+impl TryFrom<char> for Dir4 {
+    type Error = ();
+
+    /// Parses `U`, `R`, `D` or `L`, `N`, `E`, `S` or `W`, or `^`, `>`, `v` or `<` into a direction.
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        u8::try_from(value).map_err(|_| ())?.try_into()
+    }
+}
+
+impl FromStr for Dir4 {
+    type Err = ();
+
+    /// Parses a single character as for [`Dir4::try_from`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.as_bytes() {
+            &[byte] => byte.try_into(),
+            _ => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for Dir4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.arrow())
+    }
+}
+
+/// One of the eight orthogonal or diagonal compass directions, where north is negative `y` as in [`Vec2::n`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Dir8 {
+    /// All eight directions in clockwise order, starting from north.
+    pub const ALL: [Self; 8] = [
+        Self::N,
+        Self::NE,
+        Self::E,
+        Self::SE,
+        Self::S,
+        Self::SW,
+        Self::W,
+        Self::NW,
+    ];
+
+    /// Returns the position of `self` in [`Dir8::ALL`], for use in indexing arrays.
+    #[inline(always)]
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the direction at position `index` in [`Dir8::ALL`], wrapping around.
+    #[inline]
+    pub const fn from_index(index: usize) -> Self {
+        Self::ALL[index % 8]
+    }
+
+    /// Returns the direction an eighth of a turn anticlockwise from `self`.
+    #[inline]
+    pub const fn turn_left(self) -> Self {
+        Self::from_index(self.index() + 7)
+    }
+
+    /// Returns the direction an eighth of a turn clockwise from `self`.
+    #[inline]
+    pub const fn turn_right(self) -> Self {
+        Self::from_index(self.index() + 1)
+    }
+
+    /// Returns the direction opposite `self`.
+    #[inline]
+    pub const fn opposite(self) -> Self {
+        Self::from_index(self.index() + 4)
+    }
+
+    /// Returns `self` turned a quarter or half turn in the given direction.
+    #[inline]
+    pub const fn turn(self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => Self::from_index(self.index() + 6),
+            Turn::Right => Self::from_index(self.index() + 2),
+            Turn::Reverse => self.opposite(),
+        }
+    }
+
+    /// Returns whether `self` is one of the four orthogonal directions.
+    #[inline]
+    pub const fn is_orth(self) -> bool {
+        self.index().is_multiple_of(2)
+    }
+
+    /// Returns the vector pointing in direction `self` with components in `-1..=1`.
+    #[inline]
+    pub fn to_vec<T: Signed>(self) -> Vec2<T> {
+        match self {
+            Self::N => Vec2::n(),
+            Self::NE => Vec2::ne(),
+            Self::E => Vec2::e(),
+            Self::SE => Vec2::se(),
+            Self::S => Vec2::s(),
+            Self::SW => Vec2::sw(),
+            Self::W => Vec2::w(),
+            Self::NW => Vec2::nw(),
+        }
+    }
+
+    /// Returns the arrow character pointing in direction `self`, one of `↑`, `↗`, `→`, `↘`, `↓`, `↙`, `←` or `↖`.
+    #[inline]
+    pub const fn arrow(self) -> char {
+        ['↑', '↗', '→', '↘', '↓', '↙', '←', '↖'][self.index()]
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    #[inline]
+    fn from(value: Dir4) -> Self {
+        Self::from_index(value.index() * 2)
+    }
+}
+
+impl TryFrom<Dir8> for Dir4 {
+    type Error = ();
+
+    /// Converts an orthogonal direction into a `Dir4`.
+    #[inline]
+    fn try_from(value: Dir8) -> Result<Self, Self::Error> {
+        if value.is_orth() {
+            Ok(Self::from_index(value.index() / 2))
+        } else {
+            Err(())
+        }
+    }
+}
+
+impl<T: Signed> From<Dir8> for Vec2<T> {
+    #[inline]
+    fn from(value: Dir8) -> Self {
+        value.to_vec()
+    }
+}
+
+impl<T: Signed + PartialEq> TryFrom<Vec2<T>> for Dir8 {
+    type Error = ();
+
+    /// Converts a vector with components in `-1..=1` into the direction it points in.
+    fn try_from(value: Vec2<T>) -> Result<Self, Self::Error> {
+        Self::ALL
+            .into_iter()
+            .find(|dir| dir.to_vec::<T>() == value)
+            .ok_or(())
+    }
+}
+
+impl TryFrom<u8> for Dir8 {
+    type Error = ();
+
+    /// Parses an orthogonal direction as for [`Dir4::try_from`].
+    ///
+    /// The diagonal arrows returned by [`Dir8::arrow`] are not ASCII, so they can only be parsed from a `char`.
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Dir4::try_from(value).map(Self::from)
+    }
+}
+
+impl TryFrom<char> for Dir8 {
+    type Error = ();
+
+    /// Parses an orthogonal direction as for [`Dir4::try_from`], or one of the arrows returned by [`Dir8::arrow`].
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        if let Some(i) = Self::ALL.iter().position(|dir| dir.arrow() == value) {
+            return Ok(Self::ALL[i]);
+        }
+        u8::try_from(value).map_err(|_| ())?.try_into()
+    }
+}
+
+impl FromStr for Dir8 {
+    type Err = ();
+
+    /// Parses one of `N`, `NE`, `E`, `SE`, `S`, `SW`, `W` or `NW`,
+    /// or a single character as for [`Dir8::try_from`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "NE" => Ok(Self::NE),
+            "SE" => Ok(Self::SE),
+            "SW" => Ok(Self::SW),
+            "NW" => Ok(Self::NW),
+            _ => {
+                let mut chars = s.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => c.try_into(),
+                    _ => Err(()),
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for Dir8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.arrow())
+    }
+}
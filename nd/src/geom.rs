@@ -0,0 +1,427 @@
+//! Exact geometry of lines, rays and segments in 2D and 3D.
+//!
+//! Every path is parametrised as `origin + t * dir` over a range of `t`.
+//! With integer types, intersections are computed without division,
+//! and intersection points are returned as [`Rational`] points.
+
+use std::cmp::Ordering;
+
+use num_traits::Signed;
+
+use crate::{
+    traits::{Coords, Field, FieldOps},
+    vector::{Vec2, Vec3},
+};
+
+/// A point with rational coordinates `numer / denom`, where `denom` is positive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational<V, T> {
+    pub numer: V,
+    pub denom: T,
+}
+
+impl<V, T> Rational<V, T>
+where
+    V: Coords<Scalar = T>,
+    T: Signed + Clone + PartialOrd,
+{
+    /// Returns whether every coordinate of the point is an integer.
+    pub fn is_integral(&self) -> bool {
+        (0..V::DIM).all(|axis| (self.numer.axis(axis).clone() % self.denom.clone()).is_zero())
+    }
+
+    /// Returns the point with integer coordinates, or `None` if it has a non-integer coordinate.
+    pub fn to_integral(&self) -> Option<V> {
+        if !self.is_integral() {
+            return None;
+        }
+        let mut point = self.numer.clone();
+        for axis in 0..V::DIM {
+            let value = point.axis(axis).clone() / self.denom.clone();
+            *point.axis_mut(axis) = value;
+        }
+        Some(point)
+    }
+
+    /// Returns whether the point is between `min` and `max` inclusive along each axis.
+    pub fn in_bounds(&self, min: &V, max: &V) -> bool {
+        (0..V::DIM).all(|axis| {
+            let value = self.numer.axis(axis);
+            &(min.axis(axis).clone() * self.denom.clone()) <= value
+                && value <= &(max.axis(axis).clone() * self.denom.clone())
+        })
+    }
+}
+
+/// Returns `dir` divided by the gcd of its components, along with the gcd.
+///
+/// Two non-zero vectors point in the same direction exactly when their reduced forms are equal.
+/// The zero vector is returned unchanged, with a gcd of `0`.
+pub fn reduce_2d<T: Signed + Clone>(dir: &Vec2<T>) -> (Vec2<T>, T) {
+    let gcd = gcd(dir.x.clone(), dir.y.clone());
+    if gcd.is_zero() {
+        return (dir.clone(), gcd);
+    }
+    let reduced = Vec2::new(dir.x.clone() / gcd.clone(), dir.y.clone() / gcd.clone());
+    (reduced, gcd)
+}
+
+/// Returns `dir` divided by the gcd of its components, along with the gcd.
+///
+/// Two non-zero vectors point in the same direction exactly when their reduced forms are equal.
+/// The zero vector is returned unchanged, with a gcd of `0`.
+pub fn reduce_3d<T: Signed + Clone>(dir: &Vec3<T>) -> (Vec3<T>, T) {
+    let gcd = gcd(gcd(dir.x.clone(), dir.y.clone()), dir.z.clone());
+    if gcd.is_zero() {
+        return (dir.clone(), gcd);
+    }
+    let reduced = Vec3::new(
+        dir.x.clone() / gcd.clone(),
+        dir.y.clone() / gcd.clone(),
+        dir.z.clone() / gcd.clone(),
+    );
+    (reduced, gcd)
+}
+
+impl<T: Field + Signed> Vec2<T>
+where
+    for<'a> &'a T: FieldOps<T>,
+{
+    /// Returns whether `self`, `b` and `c` lie on a single line.
+    pub fn collinear(&self, b: &Self, c: &Self) -> bool {
+        (b - self).perp_dot(c - self).is_zero()
+    }
+}
+
+impl<T: Field + Signed + Ord> Vec2<T>
+where
+    for<'a> &'a T: FieldOps<T>,
+{
+    /// Compares the angles of the non-zero vectors `a` and `b`,
+    /// each measured from `self` in the direction of [`Vec2::perp`] and in the range `[0, 2π)`.
+    ///
+    /// Vectors pointing in the same direction compare equal.
+    /// For a clockwise sweep on screen starting upwards, call this on [`Vec2::n`].
+    pub fn cmp_angle(&self, a: &Self, b: &Self) -> Ordering {
+        let half = |v: &Self| {
+            let cross = self.perp_dot(v);
+            !(cross.is_positive() || (cross.is_zero() && self.dot(v).is_positive()))
+        };
+        half(a)
+            .cmp(&half(b))
+            .then_with(|| b.perp_dot(a).cmp(&T::zero()))
+    }
+}
+
+impl<T: Field + Signed> Vec3<T>
+where
+    for<'a> &'a T: FieldOps<T>,
+{
+    /// Returns whether `self`, `b` and `c` lie on a single line.
+    pub fn collinear(&self, b: &Self, c: &Self) -> bool {
+        (b - self).cross(c - self) == Vec3::zero()
+    }
+}
+
+/// A straight path in 2D of points `origin + t * dir` over some range of `t`.
+pub trait Path2<T: Field + Signed + PartialOrd>
+where
+    for<'a> &'a T: FieldOps<T>,
+{
+    /// Returns the point at `t = 0`.
+    fn origin(&self) -> Vec2<T>;
+
+    /// Returns the change in position per unit of `t`.
+    fn dir(&self) -> Vec2<T>;
+
+    /// Returns whether `t = numer / denom` is on the path, where `denom` is positive.
+    fn contains_param(&self, numer: &T, denom: &T) -> bool;
+
+    /// Returns the points at the finite ends of the path.
+    fn endpoints(&self) -> Vec<Vec2<T>>;
+
+    /// Returns the line containing the path.
+    fn line(&self) -> Line2<T> {
+        Line2::new(self.origin(), self.dir())
+    }
+
+    /// Returns whether `point` is on the path.
+    fn contains(&self, point: &Vec2<T>) -> bool {
+        let (offset, dir) = (point - &self.origin(), self.dir());
+        if dir == Vec2::zero() {
+            return offset == Vec2::zero();
+        }
+        dir.perp_dot(&offset).is_zero() && self.contains_param(&offset.dot(&dir), &dir.len_sq())
+    }
+
+    /// Returns whether `self` and `other` are parallel, including if they lie on the same line.
+    fn is_parallel(&self, other: &impl Path2<T>) -> bool {
+        self.dir().perp_dot(other.dir()).is_zero()
+    }
+
+    /// Returns the single point where `self` and `other` meet,
+    /// or `None` if they do not meet or are parallel.
+    fn intersection(&self, other: &impl Path2<T>) -> Option<Rational<Vec2<T>, T>> {
+        let (p, d) = (self.origin(), self.dir());
+        let (q, e) = (other.origin(), other.dir());
+        let mut denom = d.perp_dot(&e);
+        if denom.is_zero() {
+            return None;
+        }
+        let offset = &q - &p;
+        let (mut t, mut s) = (offset.perp_dot(&e), offset.perp_dot(&d));
+        if denom.is_negative() {
+            (denom, t, s) = (-denom, -t, -s);
+        }
+        if !self.contains_param(&t, &denom) || !other.contains_param(&s, &denom) {
+            return None;
+        }
+        let numer = &p * &denom + &d * &t;
+        Some(Rational { numer, denom })
+    }
+
+    /// Returns whether `self` and `other` have any point in common, including if they overlap.
+    fn intersects(&self, other: &impl Path2<T>) -> bool {
+        if !self.is_parallel(other) {
+            return self.intersection(other).is_some();
+        }
+        let offset = &other.origin() - &self.origin();
+        if !self.dir().perp_dot(&offset).is_zero() || !other.dir().perp_dot(&offset).is_zero() {
+            return false;
+        }
+        let (a, b) = (self.endpoints(), other.endpoints());
+        a.is_empty()
+            || b.is_empty()
+            || a.iter().any(|point| other.contains(point))
+            || b.iter().any(|point| self.contains(point))
+    }
+}
+
+/// A straight path in 3D of points `origin + t * dir` over some range of `t`.
+pub trait Path3<T: Field + Signed + PartialOrd>
+where
+    for<'a> &'a T: FieldOps<T>,
+{
+    /// Returns the point at `t = 0`.
+    fn origin(&self) -> Vec3<T>;
+
+    /// Returns the change in position per unit of `t`.
+    fn dir(&self) -> Vec3<T>;
+
+    /// Returns whether `t = numer / denom` is on the path, where `denom` is positive.
+    fn contains_param(&self, numer: &T, denom: &T) -> bool;
+
+    /// Returns the points at the finite ends of the path.
+    fn endpoints(&self) -> Vec<Vec3<T>>;
+
+    /// Returns the line containing the path.
+    fn line(&self) -> Line3<T> {
+        Line3::new(self.origin(), self.dir())
+    }
+
+    /// Returns whether `point` is on the path.
+    fn contains(&self, point: &Vec3<T>) -> bool {
+        let (offset, dir) = (point - &self.origin(), self.dir());
+        if dir == Vec3::zero() {
+            return offset == Vec3::zero();
+        }
+        dir.cross(&offset) == Vec3::zero() && self.contains_param(&offset.dot(&dir), &dir.len_sq())
+    }
+
+    /// Returns whether `self` and `other` are parallel, including if they lie on the same line.
+    fn is_parallel(&self, other: &impl Path3<T>) -> bool {
+        self.dir().cross(other.dir()) == Vec3::zero()
+    }
+
+    /// Returns the single point where `self` and `other` meet,
+    /// or `None` if they do not meet, are skew or are parallel.
+    fn intersection(&self, other: &impl Path3<T>) -> Option<Rational<Vec3<T>, T>> {
+        let (p, d) = (self.origin(), self.dir());
+        let (q, e) = (other.origin(), other.dir());
+        let normal = d.cross(&e);
+        if normal == Vec3::zero() {
+            return None;
+        }
+        let offset = &q - &p;
+        if !offset.dot(&normal).is_zero() {
+            return None;
+        }
+        let denom = normal.len_sq();
+        let t = offset.cross(&e).dot(&normal);
+        let s = offset.cross(&d).dot(&normal);
+        if !self.contains_param(&t, &denom) || !other.contains_param(&s, &denom) {
+            return None;
+        }
+        let numer = &p * &denom + &d * &t;
+        Some(Rational { numer, denom })
+    }
+
+    /// Returns whether `self` and `other` have any point in common, including if they overlap.
+    fn intersects(&self, other: &impl Path3<T>) -> bool {
+        if !self.is_parallel(other) {
+            return self.intersection(other).is_some();
+        }
+        let offset = &other.origin() - &self.origin();
+        if self.dir().cross(&offset) != Vec3::zero() || other.dir().cross(&offset) != Vec3::zero() {
+            return false;
+        }
+        let (a, b) = (self.endpoints(), other.endpoints());
+        a.is_empty()
+            || b.is_empty()
+            || a.iter().any(|point| other.contains(point))
+            || b.iter().any(|point| self.contains(point))
+    }
+}
+
+/// A line in 2D through `point` in direction `dir`, which must be non-zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Line2<T> {
+    pub point: Vec2<T>,
+    pub dir: Vec2<T>,
+}
+
+/// A ray in 2D starting at `origin` and extending in direction `dir`, which must be non-zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ray2<T> {
+    pub origin: Vec2<T>,
+    pub dir: Vec2<T>,
+}
+
+/// A line segment in 2D from `start` to `end` inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Segment2<T> {
+    pub start: Vec2<T>,
+    pub end: Vec2<T>,
+}
+
+/// A line in 3D through `point` in direction `dir`, which must be non-zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Line3<T> {
+    pub point: Vec3<T>,
+    pub dir: Vec3<T>,
+}
+
+/// A ray in 3D starting at `origin` and extending in direction `dir`, which must be non-zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ray3<T> {
+    pub origin: Vec3<T>,
+    pub dir: Vec3<T>,
+}
+
+/// A line segment in 3D from `start` to `end` inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Segment3<T> {
+    pub start: Vec3<T>,
+    pub end: Vec3<T>,
+}
+
+macro_rules! impl_paths {
+    ($vec:ident, $path:ident, $line:ident, $ray:ident, $segment:ident) => {
+        impl<T> $line<T> {
+            /// Creates a new line through `point` in direction `dir`.
+            #[inline(always)]
+            pub const fn new(point: $vec<T>, dir: $vec<T>) -> Self {
+                Self { point, dir }
+            }
+        }
+
+        impl<T: Signed + Clone> $line<T> {
+            /// Creates a new line through `a` and `b`, which must be distinct.
+            pub fn through(a: $vec<T>, b: $vec<T>) -> Self {
+                Self::new(a.clone(), b - a)
+            }
+        }
+
+        impl<T> $ray<T> {
+            /// Creates a new ray starting at `origin` in direction `dir`.
+            #[inline(always)]
+            pub const fn new(origin: $vec<T>, dir: $vec<T>) -> Self {
+                Self { origin, dir }
+            }
+        }
+
+        impl<T> $segment<T> {
+            /// Creates a new segment from `start` to `end` inclusive.
+            #[inline(always)]
+            pub const fn new(start: $vec<T>, end: $vec<T>) -> Self {
+                Self { start, end }
+            }
+        }
+
+        impl<T: Field + Signed + PartialOrd> $path<T> for $line<T>
+        where
+            for<'a> &'a T: FieldOps<T>,
+        {
+            fn origin(&self) -> $vec<T> {
+                self.point.clone()
+            }
+
+            fn dir(&self) -> $vec<T> {
+                self.dir.clone()
+            }
+
+            fn contains_param(&self, _: &T, _: &T) -> bool {
+                true
+            }
+
+            fn endpoints(&self) -> Vec<$vec<T>> {
+                vec![]
+            }
+        }
+
+        impl<T: Field + Signed + PartialOrd> $path<T> for $ray<T>
+        where
+            for<'a> &'a T: FieldOps<T>,
+        {
+            fn origin(&self) -> $vec<T> {
+                self.origin.clone()
+            }
+
+            fn dir(&self) -> $vec<T> {
+                self.dir.clone()
+            }
+
+            fn contains_param(&self, numer: &T, _: &T) -> bool {
+                !numer.is_negative()
+            }
+
+            fn endpoints(&self) -> Vec<$vec<T>> {
+                vec![self.origin.clone()]
+            }
+        }
+
+        impl<T: Field + Signed + PartialOrd> $path<T> for $segment<T>
+        where
+            for<'a> &'a T: FieldOps<T>,
+        {
+            fn origin(&self) -> $vec<T> {
+                self.start.clone()
+            }
+
+            fn dir(&self) -> $vec<T> {
+                &self.end - &self.start
+            }
+
+            fn contains_param(&self, numer: &T, denom: &T) -> bool {
+                !numer.is_negative() && numer <= denom
+            }
+
+            fn endpoints(&self) -> Vec<$vec<T>> {
+                vec![self.start.clone(), self.end.clone()]
+            }
+        }
+    };
+}
+
+impl_paths!(Vec2, Path2, Line2, Ray2, Segment2);
+impl_paths!(Vec3, Path3, Line3, Ray3, Segment3);
+
+fn gcd<T: Signed + Clone>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while !b.is_zero() {
+        let r = a % b.clone();
+        a = b;
+        b = r;
+    }
+    a
+}
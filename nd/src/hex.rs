@@ -0,0 +1,246 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use num_traits::{Euclid, Signed};
+
+use crate::vector::Vec2;
+
+/// A hexagonal grid position in axial coordinates, for a grid of pointy-topped hexagons.
+///
+/// `q` increases towards the east and `r` increases towards the south-east,
+/// with the third cube coordinate given by [`Hex::s`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Hex<T> {
+    pub q: T,
+    pub r: T,
+}
+
+impl<T> Hex<T> {
+    /// Creates a new position with the given `q` and `r` values.
+    #[inline(always)]
+    pub const fn new(q: T, r: T) -> Self {
+        Self { q, r }
+    }
+}
+
+impl<T: Signed + Clone> Hex<T> {
+    /// Returns the origin.
+    #[inline]
+    pub fn zero() -> Self {
+        Self::new(T::zero(), T::zero())
+    }
+
+    /// Returns the offset to the eastern neighbour.
+    #[inline]
+    pub fn e() -> Self {
+        Self::new(T::one(), T::zero())
+    }
+
+    /// Returns the offset to the north-eastern neighbour.
+    #[inline]
+    pub fn ne() -> Self {
+        Self::new(T::one(), -T::one())
+    }
+
+    /// Returns the offset to the north-western neighbour.
+    #[inline]
+    pub fn nw() -> Self {
+        Self::new(T::zero(), -T::one())
+    }
+
+    /// Returns the offset to the western neighbour.
+    #[inline]
+    pub fn w() -> Self {
+        Self::new(-T::one(), T::zero())
+    }
+
+    /// Returns the offset to the south-western neighbour.
+    #[inline]
+    pub fn sw() -> Self {
+        Self::new(-T::one(), T::one())
+    }
+
+    /// Returns the offset to the south-eastern neighbour.
+    #[inline]
+    pub fn se() -> Self {
+        Self::new(T::zero(), T::one())
+    }
+
+    /// Returns the offsets to the six neighbours, anticlockwise from east.
+    #[inline]
+    pub fn adj() -> [Self; 6] {
+        [
+            Self::e(),
+            Self::ne(),
+            Self::nw(),
+            Self::w(),
+            Self::sw(),
+            Self::se(),
+        ]
+    }
+
+    /// Returns the six neighbours of `self`, anticlockwise from east.
+    pub fn neighbours(&self) -> [Self; 6] {
+        Self::adj().map(|offset| self.clone() + offset)
+    }
+
+    /// Returns the offset for a single step given by one of `e`, `ne`, `nw`, `w`, `sw` or `se`,
+    /// or `None` if `step` is not one of these.
+    pub fn step(step: &str) -> Option<Self> {
+        match step {
+            "e" => Some(Self::e()),
+            "ne" => Some(Self::ne()),
+            "nw" => Some(Self::nw()),
+            "w" => Some(Self::w()),
+            "sw" => Some(Self::sw()),
+            "se" => Some(Self::se()),
+            _ => None,
+        }
+    }
+
+    /// Returns the sum of the steps in a string of concatenated steps such as `"esenee"`,
+    /// or `None` if the string is not made up of valid steps.
+    pub fn parse_steps(steps: &str) -> Option<Self> {
+        let mut pos = Self::zero();
+        let mut rest = steps;
+        while !rest.is_empty() {
+            let len = if rest.starts_with(['n', 's']) { 2 } else { 1 };
+            pos = pos + Self::step(rest.get(..len)?)?;
+            rest = &rest[len..];
+        }
+        Some(pos)
+    }
+
+    /// Returns the third cube coordinate, such that `q + r + s == 0`.
+    #[inline]
+    pub fn s(&self) -> T {
+        -(self.q.clone() + self.r.clone())
+    }
+
+    /// Returns the number of steps from the origin to `self`.
+    pub fn len(&self) -> T {
+        let two = T::one() + T::one();
+        (self.q.abs() + self.r.abs() + self.s().abs()) / two
+    }
+
+    /// Returns the number of steps between `self` and `other`.
+    pub fn distance(&self, other: &Self) -> T {
+        (self.clone() - other.clone()).len()
+    }
+
+    /// Returns `self` rotated clockwise by 60 degrees about the origin.
+    #[inline]
+    pub fn rotate_cw(&self) -> Self {
+        Self::new(-self.r.clone(), -self.s())
+    }
+
+    /// Returns `self` rotated anticlockwise by 60 degrees about the origin.
+    #[inline]
+    pub fn rotate_ccw(&self) -> Self {
+        Self::new(-self.s(), -self.q.clone())
+    }
+
+    /// Returns `self` rotated clockwise by `sixth_turns` multiples of 60 degrees about the origin.
+    pub fn rotate(&self, sixth_turns: i32) -> Self {
+        (0..sixth_turns.rem_euclid(6)).fold(self.clone(), |hex, _| hex.rotate_cw())
+    }
+
+    /// Iterator over the positions exactly `radius` steps from `self`,
+    /// starting from the south-west corner and moving anticlockwise.
+    ///
+    /// A ring of radius `0` contains only `self`.
+    pub fn ring(&self, radius: usize) -> impl Iterator<Item = Self> {
+        let centre = self.clone();
+        let start = (0..radius).fold(centre.clone(), |hex, _| hex + Self::sw());
+        Self::adj()
+            .into_iter()
+            .flat_map(move |step| std::iter::repeat_n(step, radius))
+            .scan(start, |hex, step| {
+                let next = hex.clone() + step;
+                Some(std::mem::replace(hex, next))
+            })
+            .chain((radius == 0).then_some(centre))
+    }
+
+    /// Iterator over the positions at most `radius` steps from `self`, in rings of increasing radius.
+    pub fn spiral(&self, radius: usize) -> impl Iterator<Item = Self> {
+        let centre = self.clone();
+        (0..=radius).flat_map(move |r| centre.ring(r))
+    }
+}
+
+impl<T: Signed + Euclid + Clone> Hex<T> {
+    /// Returns the offset coordinates of `self`, where odd rows are shifted half a hexagon to the east.
+    pub fn to_offset(&self) -> Vec2<T> {
+        let two = T::one() + T::one();
+        let shift = (self.r.clone() - self.r.rem_euclid(&two)) / two;
+        Vec2::new(self.q.clone() + shift, self.r.clone())
+    }
+
+    /// Returns the position with the given offset coordinates, as for [`Hex::to_offset`].
+    pub fn from_offset(pos: Vec2<T>) -> Self {
+        let two = T::one() + T::one();
+        let shift = (pos.y.clone() - pos.y.rem_euclid(&two)) / two;
+        Self::new(pos.x - shift, pos.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Hex<T> {
+    type Output = Self;
+
+    #[track_caller]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.q + rhs.q, self.r + rhs.r)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Hex<T> {
+    type Output = Self;
+
+    #[track_caller]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.q - rhs.q, self.r - rhs.r)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Hex<T> {
+    #[track_caller]
+    fn add_assign(&mut self, rhs: Self) {
+        self.q += rhs.q;
+        self.r += rhs.r;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Hex<T> {
+    #[track_caller]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.q -= rhs.q;
+        self.r -= rhs.r;
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Hex<T> {
+    type Output = Self;
+
+    #[track_caller]
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.q * rhs, self.r * rhs)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Hex<T> {
+    type Output = Self;
+
+    #[track_caller]
+    fn neg(self) -> Self::Output {
+        Self::new(-self.q, -self.r)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Hex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.q, self.r)
+    }
+}
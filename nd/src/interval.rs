@@ -0,0 +1,396 @@
+use std::{
+    fmt,
+    ops::{Add, Range, Sub},
+    slice, vec,
+};
+
+use num_traits::{One, Zero};
+
+/// A half-open interval of values `start..end`, which is empty if `start >= end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T> Interval<T> {
+    /// Creates a new interval containing the values from `start` up to but not including `end`.
+    #[inline(always)]
+    pub const fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+}
+
+impl<T: One + Add<Output = T>> Interval<T> {
+    /// Creates a new interval containing the values from `first` up to and including `last`.
+    #[inline]
+    pub fn from_inclusive(first: T, last: T) -> Self {
+        Self::new(first, last + T::one())
+    }
+}
+
+impl<T: Ord + Clone> Interval<T> {
+    /// Returns whether the interval contains no values.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    /// Returns whether `value` is in the interval.
+    #[inline]
+    pub fn contains(&self, value: &T) -> bool {
+        &self.start <= value && value < &self.end
+    }
+
+    /// Returns whether every value in `other` is in `self`.
+    pub fn covers(&self, other: &Self) -> bool {
+        other.is_empty() || (self.start <= other.start && other.end <= self.end)
+    }
+
+    /// Returns whether `self` and `other` have any values in common.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start.clone().max(other.start.clone()) < self.end.clone().min(other.end.clone())
+    }
+
+    /// Returns the interval of values in both `self` and `other`, or `None` if there are none.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let intersection = Self::new(
+            self.start.clone().max(other.start.clone()),
+            self.end.clone().min(other.end.clone()),
+        );
+        (!intersection.is_empty()).then_some(intersection)
+    }
+
+    /// Returns the smallest interval containing every value in `self` and `other`.
+    pub fn hull(&self, other: &Self) -> Self {
+        if self.is_empty() {
+            return other.clone();
+        }
+        if other.is_empty() {
+            return self.clone();
+        }
+        Self::new(
+            self.start.clone().min(other.start.clone()),
+            self.end.clone().max(other.end.clone()),
+        )
+    }
+
+    /// Returns the values in either `self` or `other` as sorted, disjoint, non-empty intervals.
+    pub fn union(&self, other: &Self) -> Vec<Self> {
+        let (first, second) = if self.start <= other.start {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        if first.is_empty() || second.is_empty() || first.end >= second.start {
+            let hull = self.hull(other);
+            return if hull.is_empty() { vec![] } else { vec![hull] };
+        }
+        vec![first.clone(), second.clone()]
+    }
+
+    /// Returns the values in `self` but not in `other` as sorted, disjoint, non-empty intervals.
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        if !self.overlaps(other) {
+            return if self.is_empty() {
+                vec![]
+            } else {
+                vec![self.clone()]
+            };
+        }
+        let mut pieces = Vec::with_capacity(2);
+        if self.start < other.start {
+            pieces.push(Self::new(self.start.clone(), other.start.clone()));
+        }
+        if other.end < self.end {
+            pieces.push(Self::new(other.end.clone(), self.end.clone()));
+        }
+        pieces
+    }
+}
+
+impl<T: Ord + Clone + Zero + Sub<Output = T>> Interval<T> {
+    /// Returns the number of values in the interval, or `0` if it is empty.
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::zero()
+        } else {
+            self.end.clone() - self.start.clone()
+        }
+    }
+}
+
+impl<T> From<Range<T>> for Interval<T> {
+    #[inline]
+    fn from(value: Range<T>) -> Self {
+        Self::new(value.start, value.end)
+    }
+}
+
+impl<T> From<Interval<T>> for Range<T> {
+    #[inline]
+    fn from(value: Interval<T>) -> Self {
+        value.start..value.end
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {})", self.start, self.end)
+    }
+}
+
+/// A set of values stored as a sorted list of disjoint, non-adjacent, non-empty intervals.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> IntervalSet<T> {
+    /// Creates a new empty set.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+
+    /// Returns whether the set contains no values.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Returns the sorted intervals making up the set.
+    #[inline(always)]
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    /// Iterator over the sorted intervals making up the set.
+    #[inline(always)]
+    pub fn iter(&self) -> slice::Iter<'_, Interval<T>> {
+        self.intervals.iter()
+    }
+}
+
+impl<T: Ord + Clone> IntervalSet<T> {
+    /// Adds the values in `interval` to the set.
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        let lo = self.intervals.partition_point(|i| i.end < interval.start);
+        let hi = self.intervals.partition_point(|i| i.start <= interval.end);
+        let merged = self.intervals[lo..hi]
+            .iter()
+            .fold(interval, |acc, i| acc.hull(i));
+        self.intervals.splice(lo..hi, [merged]);
+    }
+
+    /// Removes the values in `interval` from the set.
+    pub fn remove(&mut self, interval: &Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        let lo = self.intervals.partition_point(|i| i.end <= interval.start);
+        let hi = self.intervals.partition_point(|i| i.start < interval.end);
+        let pieces: Vec<_> = self.intervals[lo..hi]
+            .iter()
+            .flat_map(|i| i.difference(interval))
+            .collect();
+        self.intervals.splice(lo..hi, pieces);
+    }
+
+    /// Returns whether `value` is in the set.
+    pub fn contains(&self, value: &T) -> bool {
+        let i = self.intervals.partition_point(|i| &i.end <= value);
+        self.intervals.get(i).is_some_and(|i| i.contains(value))
+    }
+
+    /// Returns whether every value in `interval` is in the set.
+    pub fn covers(&self, interval: &Interval<T>) -> bool {
+        if interval.is_empty() {
+            return true;
+        }
+        let i = self.intervals.partition_point(|i| i.end <= interval.start);
+        self.intervals.get(i).is_some_and(|i| i.covers(interval))
+    }
+
+    /// Returns the smallest interval containing every value in the set, or `None` if it is empty.
+    pub fn hull(&self) -> Option<Interval<T>> {
+        let first = self.intervals.first()?;
+        let last = self.intervals.last()?;
+        Some(Interval::new(first.start.clone(), last.end.clone()))
+    }
+
+    /// Returns the set of values in either `self` or `other`.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        union.extend(other.iter().cloned());
+        union
+    }
+
+    /// Returns the set of values in both `self` and `other`.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (a, b) = (&self.intervals[i], &other.intervals[j]);
+            intervals.extend(a.intersection(b));
+            if a.end <= b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { intervals }
+    }
+
+    /// Returns the set of values in `self` but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        for interval in other {
+            difference.remove(interval);
+        }
+        difference
+    }
+}
+
+impl<T: Ord + Clone + Zero + Sub<Output = T>> IntervalSet<T> {
+    /// Returns the total number of values in the set.
+    pub fn len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::zero(), |acc, i| acc + i.len())
+    }
+}
+
+impl<T> Default for IntervalSet<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord + Clone> Extend<Interval<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = Interval<T>>>(&mut self, iter: I) {
+        for interval in iter {
+            self.insert(interval);
+        }
+    }
+}
+
+impl<T: Ord + Clone> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T> IntoIterator for IntervalSet<T> {
+    type Item = Interval<T>;
+    type IntoIter = vec::IntoIter<Interval<T>>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a IntervalSet<T> {
+    type Item = &'a Interval<T>;
+    type IntoIter = slice::Iter<'a, Interval<T>>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+
+    fn set(intervals: impl IntoIterator<Item = (i32, i32)>) -> IntervalSet<i32> {
+        intervals
+            .into_iter()
+            .map(|(start, end)| Interval::new(start, end))
+            .collect()
+    }
+
+    fn pairs(set: &IntervalSet<i32>) -> Vec<(i32, i32)> {
+        set.iter().map(|i| (i.start, i.end)).collect()
+    }
+
+    #[test]
+    fn insert_merges() {
+        assert_eq!(pairs(&set([(0, 2), (2, 4)])), [(0, 4)]);
+        assert_eq!(pairs(&set([(2, 4), (0, 2)])), [(0, 4)]);
+        assert_eq!(pairs(&set([(0, 1), (2, 3)])), [(0, 1), (2, 3)]);
+        assert_eq!(pairs(&set([(0, 1), (3, 4), (6, 7), (1, 6)])), [(0, 7)]);
+        assert_eq!(
+            pairs(&set([(0, 1), (3, 4), (8, 9), (2, 5)])),
+            [(0, 1), (2, 5), (8, 9)]
+        );
+        assert_eq!(pairs(&set([(0, 1), (5, 3)])), [(0, 1)]);
+    }
+
+    #[test]
+    fn remove_splits() {
+        let mut set = set([(0, 10), (12, 15)]);
+        set.remove(&Interval::new(3, 5));
+        assert_eq!(pairs(&set), [(0, 3), (5, 10), (12, 15)]);
+        set.remove(&Interval::new(0, 3));
+        assert_eq!(pairs(&set), [(5, 10), (12, 15)]);
+        set.remove(&Interval::new(8, 8));
+        assert_eq!(pairs(&set), [(5, 10), (12, 15)]);
+        set.remove(&Interval::new(7, 13));
+        assert_eq!(pairs(&set), [(5, 7), (13, 15)]);
+        set.remove(&Interval::new(0, 20));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn intersection() {
+        let empty = IntervalSet::new();
+        let a = set([(0, 4), (6, 10)]);
+        assert!(empty.intersection(&a).is_empty());
+        assert!(a.intersection(&empty).is_empty());
+        assert!(empty.intersection(&empty).is_empty());
+        let b = set([(2, 7), (9, 12)]);
+        assert_eq!(pairs(&a.intersection(&b)), [(2, 4), (6, 7), (9, 10)]);
+        assert_eq!(a.intersection(&b), b.intersection(&a));
+    }
+
+    #[test]
+    fn matches_value_set() {
+        let mut seed = 1u32;
+        let mut next = |n: u32| {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            (seed >> 16) % n
+        };
+        let mut set = IntervalSet::new();
+        let mut values = BTreeSet::new();
+        for _ in 0..500 {
+            let start = next(40) as i32;
+            let end = start + next(8) as i32;
+            if next(3) == 0 {
+                set.remove(&Interval::new(start, end));
+                for value in start..end {
+                    values.remove(&value);
+                }
+            } else {
+                set.insert(Interval::new(start, end));
+                values.extend(start..end);
+            }
+            assert!(set.iter().all(|i| !i.is_empty()));
+            assert!(set.intervals().windows(2).all(|w| w[0].end < w[1].start));
+            assert!((-1..50).all(|value| set.contains(&value) == values.contains(&value)));
+            assert_eq!(set.len(), values.len() as i32);
+        }
+    }
+}
@@ -1,6 +1,25 @@
+mod aabb;
+mod dir;
+pub mod geom;
+mod hex;
+mod interval;
 mod matrix;
+mod modular;
+pub mod polygon;
+mod rotation;
+mod sparse;
 pub mod traits;
 mod vector;
 
-pub use matrix::{Matrix, Row};
-pub use vector::{v, v3, v4, Vec2, Vec3, Vec4};
+pub use aabb::Aabb;
+pub use dir::{Dir4, Dir8, Turn};
+pub use hex::Hex;
+pub use interval::{Interval, IntervalSet};
+pub use matrix::{
+    BitMatrix, BitRow, ColView, ColViewMut, Cols, IntoRows, Matrix, Orientations, Positions, Row,
+    Rows, RowsMut, Span, View, ViewMut,
+};
+pub use modular::{crt, inverse_mod, mul_mod, pow_mod, Mod};
+pub use rotation::{Axis, Rotation3};
+pub use sparse::SparseGrid;
+pub use vector::{v, v3, v4, Vec2, Vec3, Vec4, VecN};
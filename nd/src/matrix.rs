@@ -1,8 +1,13 @@
 // TODO: better error messages for index conversion to and from usize.
 
+mod bits;
+mod grid;
 mod iter;
 mod linalg;
 mod ops;
+mod pattern;
+mod transform;
+mod view;
 
 use std::fmt;
 
@@ -13,10 +18,22 @@ use crate::{
     vector::{v, Vec2},
 };
 
+pub use bits::{BitMatrix, BitRow, Span};
+pub use iter::{IntoRows, Positions, Rows, RowsMut};
+pub use transform::Orientations;
+pub use view::{ColView, ColViewMut, Cols, View, ViewMut};
+
 /// A matrix type for use in linear algebra and as a 2D integer grid.
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// Elements are stored contiguously in row-major order.
+///
+/// With the `serde` feature, a matrix is encoded as its dimensions followed by its elements in row-major order.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawMatrix<T>"))]
 pub struct Matrix<T> {
-    rows: Vec<Row<T>>,
+    elems: Vec<T>,
+    dim: Vec2<usize>,
 }
 
 impl<T> Matrix<T> {
@@ -25,15 +42,22 @@ impl<T> Matrix<T> {
     /// Panics if the rows are not all the same size, or if the matrix would be empty.
     #[track_caller]
     pub fn new(rows: impl IntoIterator<Item = impl IntoIterator<Item = T>>) -> Self {
-        let mut rows: Vec<_> = rows
-            .into_iter()
-            .map(IntoIterator::into_iter)
-            .map(Iterator::collect)
-            .map(Row::new)
-            .collect();
-        assert_valid(&rows);
-        rows.shrink_to_fit();
-        Self { rows }
+        let mut elems = Vec::new();
+        let mut dim = v(0, 0);
+        for row in rows {
+            let len = elems.len();
+            elems.extend(row);
+            let width = elems.len() - len;
+            if dim.y == 0 {
+                dim.x = width;
+            } else if width != dim.x {
+                panic!("rows not all the same size");
+            }
+            dim.y += 1;
+        }
+        dim.assert_nonempty();
+        elems.shrink_to_fit();
+        Self { elems, dim }
     }
 
     /// Creates a new matrix from an iterator of elements in row-major order.
@@ -41,23 +65,19 @@ impl<T> Matrix<T> {
     /// Panics if the dimensions fail to convert to `usize`, if the matrix would be empty,
     /// or if the iterator does not yield the right number of elements to fill the dimensions exactly.
     #[track_caller]
-    #[allow(unused)]
     pub fn from_flat<P: Pos>(dim: Vec2<P>, elems: impl IntoIterator<Item = T>) -> Self {
         let dim = dim.index("dim");
         dim.assert_nonempty();
+        let len = dim.x * dim.y;
         let mut elems_iter = elems.into_iter();
-        let mut rows = Vec::with_capacity(dim.y);
-        for _ in 0..dim.y {
-            let mut elems = Vec::with_capacity(dim.x);
-            for _ in 0..dim.x {
-                elems.push(elems_iter.next().expect("not enough elements in iterator"));
-            }
-            rows.push(Row::new(elems));
+        let mut elems = Vec::with_capacity(len);
+        for _ in 0..len {
+            elems.push(elems_iter.next().expect("not enough elements in iterator"));
         }
         if elems_iter.next().is_some() {
             panic!("too many elements in iterator");
         }
-        Self { rows }
+        Self { elems, dim }
     }
 
     /// Creates a new matrix by generating elements in row-major order using a function.
@@ -67,48 +87,48 @@ impl<T> Matrix<T> {
     pub fn from_fn<P: Pos>(dim: Vec2<P>, mut f: impl FnMut() -> T) -> Self {
         let dim = dim.index("dim");
         dim.assert_nonempty();
-        let mut rows = Vec::with_capacity(dim.y);
-        for _ in 0..dim.y {
-            let mut elems = Vec::with_capacity(dim.x);
-            for _ in 0..dim.x {
-                elems.push(f());
-            }
-            rows.push(Row::new(elems));
+        let len = dim.x * dim.y;
+        let mut elems = Vec::with_capacity(len);
+        for _ in 0..len {
+            elems.push(f());
         }
-        Self { rows }
+        Self { elems, dim }
     }
 
     /// Creates a new column vector / matrix with one column.
     ///
     /// Panics if the matrix would be empty.
     pub fn col(elems: impl IntoIterator<Item = T>) -> Self {
-        let mut rows: Vec<_> = elems.into_iter().map(|elem| Row::new(vec![elem])).collect();
-        rows.shrink_to_fit();
-        let dim = v(1, rows.len());
+        let mut elems: Vec<_> = elems.into_iter().collect();
+        elems.shrink_to_fit();
+        let dim = v(1, elems.len());
         dim.assert_nonempty();
-        Self { rows }
+        Self { elems, dim }
     }
 
     /// Creates a new row vector / matrix with one row.
     ///
     /// Panics if the matrix would be empty.
     pub fn row(elems: impl IntoIterator<Item = T>) -> Self {
-        let rows = vec![Row::new(elems.into_iter().collect())];
-        let dim = v(rows[0].len(), 1);
+        let mut elems: Vec<_> = elems.into_iter().collect();
+        elems.shrink_to_fit();
+        let dim = v(elems.len(), 1);
         dim.assert_nonempty();
-        Self { rows }
+        Self { elems, dim }
     }
 
     /// Returns a shared reference to the element at the given position, or `None` if the position is out of bounds.
     pub fn get<P: Pos>(&self, pos: Vec2<P>) -> Option<&T> {
         self.get_in_bounds(pos)
-            .map(|pos| &self.rows[pos.y].elems[pos.x])
+            .map(|pos| &self.elems[self.flat_index(pos)])
     }
 
     /// Returns a mutable reference to the element at the given position, or `None` if the position is out of bounds.
     pub fn get_mut<P: Pos>(&mut self, pos: Vec2<P>) -> Option<&mut T> {
-        self.get_in_bounds(pos)
-            .map(|pos| &mut self.rows[pos.y].elems[pos.x])
+        self.get_in_bounds(pos).map(|pos| {
+            let i = self.flat_index(pos);
+            &mut self.elems[i]
+        })
     }
 
     /// Returns whether the given position is within the bounds of the matrix.
@@ -116,25 +136,92 @@ impl<T> Matrix<T> {
         self.get_in_bounds(pos).is_some()
     }
 
+    /// Returns the elements of the matrix as a slice in row-major order.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[T] {
+        &self.elems
+    }
+
+    /// Returns the elements of the matrix as a mutable slice in row-major order.
+    #[inline(always)]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.elems
+    }
+
+    /// Consumes the matrix, returning its elements in row-major order.
+    #[inline(always)]
+    pub fn into_vec(self) -> Vec<T> {
+        self.elems
+    }
+
     fn get_in_bounds<P: Pos>(&self, pos: Vec2<P>) -> Option<Vec2<usize>> {
         let dim = self.get_dim();
         pos.get_index()
             .and_then(|pos| (pos.x < dim.x && pos.y < dim.y).then_some(pos))
     }
 
-    #[inline]
+    #[inline(always)]
+    fn flat_index(&self, pos: Vec2<usize>) -> usize {
+        pos.y * self.dim.x + pos.x
+    }
+
+    #[inline(always)]
+    fn row_slice(&self, row: usize) -> &[T] {
+        &self.elems[row * self.dim.x..(row + 1) * self.dim.x]
+    }
+
+    #[inline(always)]
+    fn row_slice_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.elems[row * self.dim.x..(row + 1) * self.dim.x]
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        if a == b {
+            return;
+        }
+        let (a, b) = (a.min(b), a.max(b));
+        let width = self.dim.x;
+        let (first, second) = self.elems.split_at_mut(b * width);
+        first[a * width..(a + 1) * width].swap_with_slice(&mut second[..width]);
+    }
+
+    /// Rearranges the elements in place so that the element at each index `i` is taken from index `src(i)`,
+    /// then sets the dimensions to `dim`.
+    ///
+    /// `src` must be a permutation of the indices.
+    fn permute(&mut self, dim: Vec2<usize>, src: impl Fn(usize) -> usize) {
+        let mut visited = vec![false; self.elems.len()];
+        for start in 0..self.elems.len() {
+            if visited[start] {
+                continue;
+            }
+            let mut i = start;
+            loop {
+                visited[i] = true;
+                let j = src(i);
+                if j == start {
+                    break;
+                }
+                self.elems.swap(i, j);
+                i = j;
+            }
+        }
+        self.dim = dim;
+    }
+
+    #[inline(always)]
     pub(crate) fn get_dim(&self) -> Vec2<usize> {
-        v(self.rows[0].len(), self.rows.len())
+        self.dim
     }
 
     #[inline(always)]
     fn get_width(&self) -> usize {
-        self.rows[0].len()
+        self.dim.x
     }
 
     #[inline(always)]
     fn get_height(&self) -> usize {
-        self.rows.len()
+        self.dim.y
     }
 
     /// Returns the dimensions of the matrix.
@@ -189,7 +276,8 @@ impl<T: Clone> Matrix<T> {
         let dim = dim.index("dim");
         dim.assert_nonempty();
         Self {
-            rows: vec![Row::new(vec![value; dim.x]); dim.y],
+            elems: vec![value; dim.x * dim.y],
+            dim,
         }
     }
 }
@@ -210,22 +298,52 @@ impl<T: Clone + Zero + One> Matrix<T> {
     }
 }
 
-/// An individual row of a matrix.
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// An individual row of a matrix, borrowed from its contiguous storage.
+#[derive(Debug, PartialEq, Eq, Hash)]
+#[repr(transparent)]
 pub struct Row<T> {
-    elems: Vec<T>,
+    elems: [T],
 }
 
 impl<T> Row<T> {
     #[inline(always)]
-    fn new(elems: Vec<T>) -> Self {
-        Self { elems }
+    fn new(elems: &[T]) -> &Self {
+        // SAFETY: `Row<T>` is a `repr(transparent)` wrapper around `[T]`.
+        unsafe { &*(elems as *const [T] as *const Self) }
     }
 
     #[inline(always)]
-    fn len(&self) -> usize {
+    fn new_mut(elems: &mut [T]) -> &mut Self {
+        // SAFETY: `Row<T>` is a `repr(transparent)` wrapper around `[T]`.
+        unsafe { &mut *(elems as *mut [T] as *mut Self) }
+    }
+
+    /// Returns the number of elements in the row.
+    #[inline(always)]
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
         self.elems.len()
     }
+
+    /// Returns the elements of the row as a slice.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[T] {
+        &self.elems
+    }
+
+    /// Returns the elements of the row as a mutable slice.
+    #[inline(always)]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.elems
+    }
+}
+
+impl<T: Clone> Row<T> {
+    /// Returns a copy of the elements of the row.
+    #[inline(always)]
+    pub fn to_vec(&self) -> Vec<T> {
+        self.elems.to_vec()
+    }
 }
 
 impl Vec2<usize> {
@@ -267,19 +385,43 @@ impl<P: Pos> Vec2<P> {
     }
 }
 
-#[track_caller]
-fn assert_valid<T>(rows: &[Row<T>]) {
-    for w in rows.windows(2) {
-        if w[0].len() != w[1].len() {
-            panic!("rows not all the same size");
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Matrix<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("Matrix", 2)?;
+        state.serialize_field("dim", &self.dim)?;
+        state.serialize_field("elems", &self.elems)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(rename = "Matrix")]
+struct RawMatrix<T> {
+    dim: Vec2<usize>,
+    elems: Vec<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<T> TryFrom<RawMatrix<T>> for Matrix<T> {
+    type Error = String;
+
+    fn try_from(value: RawMatrix<T>) -> Result<Self, Self::Error> {
+        let RawMatrix { dim, elems } = value;
+        if dim.x == 0 || dim.y == 0 {
+            return Err("matrix has zero size".to_string());
+        }
+        if dim.x.checked_mul(dim.y) != Some(elems.len()) {
+            return Err(format!(
+                "wrong number of elements for dimensions {dim}: {}",
+                elems.len()
+            ));
         }
+        Ok(Self { elems, dim })
     }
-    let dim = if rows.is_empty() {
-        v(0, 0)
-    } else {
-        v(rows[0].len(), rows.len())
-    };
-    dim.assert_nonempty();
 }
 
 impl<T: fmt::Display> fmt::Display for Matrix<T> {
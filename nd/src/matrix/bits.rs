@@ -0,0 +1,536 @@
+use std::{
+    iter,
+    ops::{BitXor, BitXorAssign, Index, Mul},
+    slice,
+};
+
+use crate::{matrix::Matrix, traits::Pos, vector::Vec2};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A vector over GF(2), with elements packed into 64-bit words.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitRow {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitRow {
+    /// Creates a new vector of the given length with every element set to `false`.
+    pub fn zero(len: usize) -> Self {
+        Self {
+            words: vec![0; len.div_ceil(WORD_BITS)],
+            len,
+        }
+    }
+
+    /// Returns the number of elements in the vector.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the vector has no elements.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the element at the given index.
+    ///
+    /// Panics if the index is out of bounds.
+    #[track_caller]
+    #[inline]
+    pub fn get(&self, index: usize) -> bool {
+        self.assert_in_bounds(index);
+        self.words[index / WORD_BITS] >> (index % WORD_BITS) & 1 == 1
+    }
+
+    /// Sets the element at the given index to `value`.
+    ///
+    /// Panics if the index is out of bounds.
+    #[track_caller]
+    #[inline]
+    pub fn set(&mut self, index: usize, value: bool) {
+        self.assert_in_bounds(index);
+        let mask = 1 << (index % WORD_BITS);
+        if value {
+            self.words[index / WORD_BITS] |= mask;
+        } else {
+            self.words[index / WORD_BITS] &= !mask;
+        }
+    }
+
+    /// Flips the element at the given index.
+    ///
+    /// Panics if the index is out of bounds.
+    #[track_caller]
+    #[inline]
+    pub fn flip(&mut self, index: usize) {
+        self.assert_in_bounds(index);
+        self.words[index / WORD_BITS] ^= 1 << (index % WORD_BITS);
+    }
+
+    /// Returns the number of elements set to `true`.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Returns whether every element is `false`.
+    pub fn is_zero(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    /// Returns the dot product of `self` and `other` over GF(2).
+    ///
+    /// Panics if `self` and `other` do not have the same length.
+    #[track_caller]
+    pub fn dot(&self, other: &Self) -> bool {
+        self.assert_len_eq(other);
+        self.words
+            .iter()
+            .zip(&other.words)
+            .fold(0, |acc, (a, b)| acc ^ (a & b).count_ones())
+            & 1
+            == 1
+    }
+
+    /// Iterator over the elements of the vector.
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len).map(|i| self.get(i))
+    }
+
+    /// Iterator over the indices of elements set to `true`, in increasing order.
+    pub fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            let mut word = word;
+            iter::from_fn(move || {
+                (word != 0).then(|| {
+                    let bit = word.trailing_zeros() as usize;
+                    word &= word - 1;
+                    i * WORD_BITS + bit
+                })
+            })
+        })
+    }
+
+    fn xor_from(&mut self, other: &Self, start: usize) {
+        for (a, b) in self.words[start / WORD_BITS..]
+            .iter_mut()
+            .zip(&other.words[start / WORD_BITS..])
+        {
+            *a ^= b;
+        }
+    }
+
+    #[track_caller]
+    fn assert_in_bounds(&self, index: usize) {
+        if index >= self.len {
+            panic!(
+                "index out of bounds: length is {} but index is {index}",
+                self.len
+            );
+        }
+    }
+
+    #[track_caller]
+    fn assert_len_eq(&self, other: &Self) {
+        if self.len != other.len {
+            panic!(
+                "length of self ({}) does not match length of other ({})",
+                self.len, other.len
+            );
+        }
+    }
+}
+
+impl FromIterator<bool> for BitRow {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut row = Self::zero(0);
+        for value in iter {
+            if row.len.is_multiple_of(WORD_BITS) {
+                row.words.push(0);
+            }
+            row.len += 1;
+            if value {
+                row.flip(row.len - 1);
+            }
+        }
+        row
+    }
+}
+
+impl<P: Pos> Index<P> for BitRow {
+    type Output = bool;
+
+    #[track_caller]
+    fn index(&self, index: P) -> &Self::Output {
+        if self.get(index.index("index")) {
+            &true
+        } else {
+            &false
+        }
+    }
+}
+
+impl BitXorAssign<&BitRow> for BitRow {
+    #[track_caller]
+    fn bitxor_assign(&mut self, rhs: &BitRow) {
+        self.assert_len_eq(rhs);
+        self.xor_from(rhs, 0);
+    }
+}
+
+impl BitXor<&BitRow> for &BitRow {
+    type Output = BitRow;
+
+    #[track_caller]
+    fn bitxor(self, rhs: &BitRow) -> Self::Output {
+        let mut row = self.clone();
+        row ^= rhs;
+        row
+    }
+}
+
+/// A matrix over GF(2), with each row packed into 64-bit words.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitMatrix {
+    rows: Vec<BitRow>,
+    width: usize,
+}
+
+impl BitMatrix {
+    /// Creates a new matrix from a nested iterator of elements in rows.
+    ///
+    /// Panics if the rows are not all the same size, or if the matrix would be empty.
+    #[track_caller]
+    pub fn new(rows: impl IntoIterator<Item = impl IntoIterator<Item = bool>>) -> Self {
+        let rows: Vec<BitRow> = rows.into_iter().map(BitRow::from_iter).collect();
+        for w in rows.windows(2) {
+            if w[0].len() != w[1].len() {
+                panic!("rows not all the same size");
+            }
+        }
+        let width = rows.first().map_or(0, BitRow::len);
+        Vec2::new(width, rows.len()).assert_nonempty();
+        Self { rows, width }
+    }
+
+    /// Creates a new matrix with the given dimensions and every element set to `false`.
+    ///
+    /// Panics if the dimensions fail to convert to `usize`, or if the matrix would be empty.
+    #[track_caller]
+    pub fn zero<P: Pos>(dim: Vec2<P>) -> Self {
+        let dim = dim.index("dim");
+        dim.assert_nonempty();
+        Self {
+            rows: vec![BitRow::zero(dim.x); dim.y],
+            width: dim.x,
+        }
+    }
+
+    /// Creates an identity matrix.
+    ///
+    /// Panics if the dimensions fail to convert to `usize`, or if the matrix would be empty.
+    #[track_caller]
+    pub fn id<P: Pos>(dim: P) -> Self {
+        let dim = dim.index("dim");
+        let mut mat = Self::zero(Vec2::new(dim, dim));
+        for i in 0..dim {
+            mat.rows[i].set(i, true);
+        }
+        mat
+    }
+
+    /// Returns the element at the given position, or `None` if the position is out of bounds.
+    pub fn get<P: Pos>(&self, pos: Vec2<P>) -> Option<bool> {
+        self.get_in_bounds(pos).map(|pos| self.rows[pos.y].get(pos.x))
+    }
+
+    /// Sets the element at the given position to `value`.
+    ///
+    /// Panics if the position is out of bounds.
+    #[track_caller]
+    pub fn set<P: Pos>(&mut self, pos: Vec2<P>, value: bool) {
+        let pos = self.pos_in_bounds(pos);
+        self.rows[pos.y].set(pos.x, value);
+    }
+
+    /// Flips the element at the given position.
+    ///
+    /// Panics if the position is out of bounds.
+    #[track_caller]
+    pub fn flip<P: Pos>(&mut self, pos: Vec2<P>) {
+        let pos = self.pos_in_bounds(pos);
+        self.rows[pos.y].flip(pos.x);
+    }
+
+    /// Returns the dimensions of the matrix.
+    ///
+    /// Panics if the dimensions cannot be converted to the type `P`.
+    #[track_caller]
+    pub fn dim<P: Pos>(&self) -> Vec2<P> {
+        Vec2::new(self.width, self.rows.len()).pos("dim", "type P")
+    }
+
+    /// Returns the number of columns in the matrix.
+    ///
+    /// Panics if the value cannot be converted to the type `P`.
+    #[track_caller]
+    pub fn width<P: Pos>(&self) -> P {
+        P::pos(self.width, "width", "type P")
+    }
+
+    /// Returns the number of rows in the matrix.
+    ///
+    /// Panics if the value cannot be converted to the type `P`.
+    #[track_caller]
+    pub fn height<P: Pos>(&self) -> P {
+        P::pos(self.rows.len(), "height", "type P")
+    }
+
+    /// Iterator over shared references to rows in the matrix.
+    #[inline(always)]
+    pub fn iter(&self) -> slice::Iter<'_, BitRow> {
+        self.rows.iter()
+    }
+
+    /// Transforms `self` into reduced row echelon form.
+    #[inline(always)]
+    pub fn rref(&mut self) {
+        self.rref_pivots(self.width);
+    }
+
+    /// Returns the rank of `self`.
+    pub fn rank(&self) -> usize {
+        self.clone().rref_pivots(self.width).len()
+    }
+
+    /// Returns a basis for the nullspace of `self`, i.e. the vectors `x` such that `self * x = 0`.
+    pub fn nullspace(&self) -> Vec<BitRow> {
+        let mut mat = self.clone();
+        let pivots = mat.rref_pivots(self.width);
+        mat.nullspace_basis(self.width, &pivots)
+    }
+
+    /// Iterator over every vector in the nullspace of `self`, starting with the zero vector.
+    pub fn nullspace_iter(&self) -> Span {
+        Span::new(BitRow::zero(self.width), self.nullspace())
+    }
+
+    /// Returns a solution to the equation `self * x = rhs`, or `None` if there is no solution.
+    ///
+    /// Every free variable is set to `false`.
+    ///
+    /// Panics if the length of `rhs` does not match the height of `self`.
+    #[track_caller]
+    pub fn solve(&self, rhs: &BitRow) -> Option<BitRow> {
+        self.solve_with_nullspace(rhs).map(|(x, _)| x)
+    }
+
+    /// Iterator over every solution to the equation `self * x = rhs`, or `None` if there is no solution.
+    ///
+    /// Panics if the length of `rhs` does not match the height of `self`.
+    #[track_caller]
+    pub fn solve_iter(&self, rhs: &BitRow) -> Option<Span> {
+        self.solve_with_nullspace(rhs)
+            .map(|(x, basis)| Span::new(x, basis))
+    }
+
+    #[track_caller]
+    fn solve_with_nullspace(&self, rhs: &BitRow) -> Option<(BitRow, Vec<BitRow>)> {
+        if rhs.len() != self.rows.len() {
+            panic!(
+                "length of rhs ({}) does not match height of self ({})",
+                rhs.len(),
+                self.rows.len()
+            );
+        }
+        let mut aug = Self {
+            rows: self
+                .rows
+                .iter()
+                .zip(rhs.iter())
+                .map(|(row, b)| row.iter().chain(iter::once(b)).collect())
+                .collect(),
+            width: self.width + 1,
+        };
+        let pivots = aug.rref_pivots(self.width);
+        if aug.rows[pivots.len()..]
+            .iter()
+            .any(|row| row.get(self.width))
+        {
+            return None;
+        }
+        let mut x = BitRow::zero(self.width);
+        for (row, &col) in aug.rows.iter().zip(&pivots) {
+            x.set(col, row.get(self.width));
+        }
+        let basis = aug.nullspace_basis(self.width, &pivots);
+        Some((x, basis))
+    }
+
+    /// Reduces the first `cols` columns of `self`, returning the pivot column of each nonzero row.
+    fn rref_pivots(&mut self, cols: usize) -> Vec<usize> {
+        let mut pivots = Vec::new();
+        for col in 0..cols {
+            let row = pivots.len();
+            if row == self.rows.len() {
+                break;
+            }
+            let Some(pivot_row) = (row..self.rows.len()).find(|&r| self.rows[r].get(col)) else {
+                continue;
+            };
+            self.rows.swap(row, pivot_row);
+            let (before, rest) = self.rows.split_at_mut(row);
+            let (pivot, after) = rest.split_first_mut().unwrap();
+            for other in before.iter_mut().chain(after) {
+                if other.get(col) {
+                    other.xor_from(pivot, col);
+                }
+            }
+            pivots.push(col);
+        }
+        pivots
+    }
+
+    /// Returns a nullspace basis for the first `cols` columns of `self`,
+    /// which must be in reduced row echelon form with the given pivot columns.
+    fn nullspace_basis(&self, cols: usize, pivots: &[usize]) -> Vec<BitRow> {
+        let mut is_pivot = vec![false; cols];
+        for &col in pivots {
+            is_pivot[col] = true;
+        }
+        (0..cols)
+            .filter(|&col| !is_pivot[col])
+            .map(|free| {
+                let mut x = BitRow::zero(cols);
+                x.set(free, true);
+                for (row, &col) in self.rows.iter().zip(pivots) {
+                    if row.get(free) {
+                        x.set(col, true);
+                    }
+                }
+                x
+            })
+            .collect()
+    }
+
+    fn get_in_bounds<P: Pos>(&self, pos: Vec2<P>) -> Option<Vec2<usize>> {
+        pos.get_index()
+            .and_then(|pos| (pos.x < self.width && pos.y < self.rows.len()).then_some(pos))
+    }
+
+    #[track_caller]
+    fn pos_in_bounds<P: Pos>(&self, pos: Vec2<P>) -> Vec2<usize> {
+        if let Some(i) = self.get_in_bounds(pos.clone()) {
+            i
+        } else {
+            panic!(
+                "position out of bounds: dimensions are {} but position is ({:?}, {:?})",
+                Vec2::new(self.width, self.rows.len()),
+                pos.x,
+                pos.y
+            );
+        }
+    }
+}
+
+impl<P: Pos> Index<P> for BitMatrix {
+    type Output = BitRow;
+
+    #[track_caller]
+    fn index(&self, index: P) -> &Self::Output {
+        let row = index.index("row index");
+        if row >= self.rows.len() {
+            panic!(
+                "row index out of bounds: height is {} but row index is {row}",
+                self.rows.len()
+            );
+        }
+        &self.rows[row]
+    }
+}
+
+impl Mul<&BitRow> for &BitMatrix {
+    type Output = BitRow;
+
+    /// Multiplies `self` by the column vector `rhs`.
+    #[track_caller]
+    fn mul(self, rhs: &BitRow) -> Self::Output {
+        self.rows.iter().map(|row| row.dot(rhs)).collect()
+    }
+}
+
+impl From<&Matrix<bool>> for BitMatrix {
+    fn from(value: &Matrix<bool>) -> Self {
+        Self {
+            rows: value.iter().map(|row| row.iter().copied().collect()).collect(),
+            width: value.get_width(),
+        }
+    }
+}
+
+impl From<Matrix<bool>> for BitMatrix {
+    #[inline(always)]
+    fn from(value: Matrix<bool>) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl From<&BitMatrix> for Matrix<bool> {
+    fn from(value: &BitMatrix) -> Self {
+        Matrix::new(value.rows.iter().map(BitRow::iter))
+    }
+}
+
+impl From<BitMatrix> for Matrix<bool> {
+    #[inline(always)]
+    fn from(value: BitMatrix) -> Self {
+        Self::from(&value)
+    }
+}
+
+/// Iterator over every vector in an affine subspace over GF(2), in Gray code order.
+#[derive(Debug, Clone)]
+pub struct Span {
+    current: BitRow,
+    basis: Vec<BitRow>,
+    next: u128,
+    total: u128,
+}
+
+impl Span {
+    #[track_caller]
+    fn new(offset: BitRow, basis: Vec<BitRow>) -> Self {
+        if basis.len() >= u128::BITS as usize {
+            panic!("span has too many dimensions to enumerate: {}", basis.len());
+        }
+        Self {
+            current: offset,
+            total: 1 << basis.len(),
+            basis,
+            next: 0,
+        }
+    }
+
+    /// Returns the basis of the span.
+    pub fn basis(&self) -> &[BitRow] {
+        &self.basis
+    }
+}
+
+impl Iterator for Span {
+    type Item = BitRow;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next == self.total {
+            return None;
+        }
+        if self.next > 0 {
+            self.current ^= &self.basis[self.next.trailing_zeros() as usize];
+        }
+        self.next += 1;
+        Some(self.current.clone())
+    }
+}
@@ -0,0 +1,226 @@
+use std::ops::Add;
+
+use num_traits::{Euclid, Signed};
+
+use crate::{
+    matrix::Matrix,
+    traits::Pos,
+    vector::{v, Vec2},
+};
+
+impl<T> Matrix<T> {
+    /// Parses a matrix from lines of text, mapping each byte to an element.
+    ///
+    /// Panics if the lines are not all the same length, or if the matrix would be empty.
+    #[track_caller]
+    pub fn parse(text: &str, f: impl FnMut(u8) -> T) -> Self {
+        Self::parse_lines(text, None::<fn() -> T>, f)
+    }
+
+    /// Parses a matrix from lines of text, mapping each byte to an element,
+    /// and padding lines shorter than the longest line with `fill`.
+    ///
+    /// Panics if the matrix would be empty.
+    #[track_caller]
+    pub fn parse_padded(text: &str, fill: T, f: impl FnMut(u8) -> T) -> Self
+    where
+        T: Clone,
+    {
+        Self::parse_lines(text, Some(|| fill.clone()), f)
+    }
+
+    /// Parses a matrix from lines of text, mapping each byte to an element,
+    /// and returns the position of the first occurrence of each marker byte.
+    ///
+    /// Markers are located before mapping, so they can be mapped to ordinary elements.
+    ///
+    /// Panics if the lines are not all the same length, if the matrix would be empty,
+    /// or if a marker position cannot be converted to the type `P`.
+    #[track_caller]
+    pub fn parse_markers<P: Pos, const N: usize>(
+        text: &str,
+        markers: [u8; N],
+        f: impl FnMut(u8) -> T,
+    ) -> (Self, [Option<Vec2<P>>; N]) {
+        (Self::parse(text, f), find_markers(text, markers))
+    }
+
+    /// Parses a matrix from lines of text, mapping each byte to an element,
+    /// padding lines shorter than the longest line with `fill`,
+    /// and returns the position of the first occurrence of each marker byte.
+    ///
+    /// Markers are located before mapping, so they can be mapped to ordinary elements.
+    ///
+    /// Panics if the matrix would be empty, or if a marker position cannot be converted to the type `P`.
+    #[track_caller]
+    pub fn parse_markers_padded<P: Pos, const N: usize>(
+        text: &str,
+        fill: T,
+        markers: [u8; N],
+        f: impl FnMut(u8) -> T,
+    ) -> (Self, [Option<Vec2<P>>; N])
+    where
+        T: Clone,
+    {
+        (
+            Self::parse_padded(text, fill, f),
+            find_markers(text, markers),
+        )
+    }
+
+    /// Returns the position of the first element in row-major order that satisfies the predicate,
+    /// or `None` if there is no such element.
+    ///
+    /// Panics if the position cannot be converted to the type `P`.
+    #[track_caller]
+    pub fn position<P: Pos>(&self, pred: impl FnMut(&T) -> bool) -> Option<Vec2<P>> {
+        let width = self.get_width();
+        self.iter_all()
+            .position(pred)
+            .map(|i| v(i % width, i / width).pos("position", "type P"))
+    }
+
+    /// Renders the matrix as lines of text, mapping each element to a character.
+    ///
+    /// Lines are separated by `'\n'`, with no trailing newline.
+    pub fn render(&self, mut f: impl FnMut(&T) -> char) -> String {
+        let mut text = String::with_capacity((self.get_width() + 1) * self.get_height());
+        for (i, row) in self.iter().enumerate() {
+            if i > 0 {
+                text.push('\n');
+            }
+            text.extend(row.iter().map(&mut f));
+        }
+        text
+    }
+
+    #[track_caller]
+    fn parse_lines(
+        text: &str,
+        mut fill: Option<impl FnMut() -> T>,
+        mut f: impl FnMut(u8) -> T,
+    ) -> Self {
+        let width = text.lines().map(str::len).max().unwrap_or(0);
+        let mut elems = Vec::with_capacity(width * text.lines().count());
+        let mut dim = v(width, 0);
+        for line in text.lines() {
+            elems.extend(line.bytes().map(&mut f));
+            if line.len() < width {
+                if let Some(fill) = &mut fill {
+                    elems.extend((line.len()..width).map(|_| fill()));
+                } else {
+                    panic!("rows not all the same size");
+                }
+            }
+            dim.y += 1;
+        }
+        dim.assert_nonempty();
+        Self { elems, dim }
+    }
+
+    /// Iterator over the in-bounds positions orthogonally adjacent to `pos`, along with their elements.
+    pub fn neighbours_orth<P: Pos + Signed>(
+        &self,
+        pos: Vec2<P>,
+    ) -> impl Iterator<Item = (Vec2<P>, &T)> {
+        self.neighbours_with(pos, Vec2::orth())
+    }
+
+    /// Iterator over the in-bounds positions diagonally adjacent to `pos`, along with their elements.
+    pub fn neighbours_diag<P: Pos + Signed>(
+        &self,
+        pos: Vec2<P>,
+    ) -> impl Iterator<Item = (Vec2<P>, &T)> {
+        self.neighbours_with(pos, Vec2::diag())
+    }
+
+    /// Iterator over the in-bounds positions orthogonally or diagonally adjacent to `pos`, along with their elements.
+    pub fn neighbours_adj<P: Pos + Signed>(
+        &self,
+        pos: Vec2<P>,
+    ) -> impl Iterator<Item = (Vec2<P>, &T)> {
+        self.neighbours_with(pos, Vec2::adj())
+    }
+
+    /// Iterator over the in-bounds positions found by adding each offset to `pos`, along with their elements.
+    pub fn neighbours_with<P: Pos + Add<Output = P>>(
+        &self,
+        pos: Vec2<P>,
+        offsets: impl IntoIterator<Item = Vec2<P>>,
+    ) -> impl Iterator<Item = (Vec2<P>, &T)> {
+        offsets.into_iter().filter_map(move |offset| {
+            let neighbour = pos.clone() + offset;
+            self.get(neighbour.clone()).map(|e| (neighbour, e))
+        })
+    }
+
+    /// Returns `pos` wrapped around the edges of the matrix so that it is in bounds.
+    ///
+    /// Panics if the dimensions cannot be converted to the type `P`.
+    #[track_caller]
+    pub fn wrap<P: Pos + Euclid>(&self, pos: Vec2<P>) -> Vec2<P> {
+        let dim: Vec2<P> = self.dim();
+        Vec2::new(pos.x.rem_euclid(&dim.x), pos.y.rem_euclid(&dim.y))
+    }
+
+    /// Iterator over the positions orthogonally adjacent to `pos`, wrapping around the edges of the matrix,
+    /// along with their elements.
+    ///
+    /// Panics if the dimensions cannot be converted to the type `P`.
+    #[track_caller]
+    pub fn neighbours_orth_wrapping<P: Pos + Signed + Euclid>(
+        &self,
+        pos: Vec2<P>,
+    ) -> impl Iterator<Item = (Vec2<P>, &T)> {
+        self.neighbours_with_wrapping(pos, Vec2::orth())
+    }
+
+    /// Iterator over the positions orthogonally or diagonally adjacent to `pos`, wrapping around the edges of the matrix,
+    /// along with their elements.
+    ///
+    /// Panics if the dimensions cannot be converted to the type `P`.
+    #[track_caller]
+    pub fn neighbours_adj_wrapping<P: Pos + Signed + Euclid>(
+        &self,
+        pos: Vec2<P>,
+    ) -> impl Iterator<Item = (Vec2<P>, &T)> {
+        self.neighbours_with_wrapping(pos, Vec2::adj())
+    }
+
+    /// Iterator over the positions found by adding each offset to `pos`, wrapping around the edges of the matrix,
+    /// along with their elements.
+    ///
+    /// Panics if the dimensions cannot be converted to the type `P`.
+    #[track_caller]
+    pub fn neighbours_with_wrapping<P: Pos + Euclid + Add<Output = P>>(
+        &self,
+        pos: Vec2<P>,
+        offsets: impl IntoIterator<Item = Vec2<P>>,
+    ) -> impl Iterator<Item = (Vec2<P>, &T)> {
+        let dim: Vec2<P> = self.dim();
+        offsets.into_iter().map(move |offset| {
+            let neighbour = pos.clone() + offset;
+            let neighbour = Vec2::new(
+                neighbour.x.rem_euclid(&dim.x),
+                neighbour.y.rem_euclid(&dim.y),
+            );
+            let e = &self[neighbour.clone()];
+            (neighbour, e)
+        })
+    }
+}
+
+#[track_caller]
+fn find_markers<P: Pos, const N: usize>(text: &str, markers: [u8; N]) -> [Option<Vec2<P>>; N] {
+    let mut found = [(); N].map(|_| None);
+    for (y, line) in text.lines().enumerate() {
+        for (x, byte) in line.bytes().enumerate() {
+            if let Some(i) = markers.iter().position(|&m| m == byte) {
+                if found[i].is_none() {
+                    found[i] = Some(v(x, y).pos("marker position", "type P"));
+                }
+            }
+        }
+    }
+    found
+}
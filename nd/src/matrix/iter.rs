@@ -1,4 +1,4 @@
-use std::{marker, slice, vec};
+use std::{iter::FusedIterator, marker, slice, vec};
 
 use crate::{
     matrix::{Matrix, Row},
@@ -9,29 +9,32 @@ use crate::{
 impl<T> Matrix<T> {
     /// Iterator over shared references to rows in the matrix.
     #[inline(always)]
-    pub fn iter(&self) -> slice::Iter<Row<T>> {
+    pub fn iter(&self) -> Rows<'_, T> {
         self.into_iter()
     }
 
     /// Iterator over mutable references to rows in the matrix.
     #[inline(always)]
-    pub fn iter_mut(&mut self) -> slice::IterMut<Row<T>> {
+    pub fn iter_mut(&mut self) -> RowsMut<'_, T> {
         self.into_iter()
     }
 
     /// Iterator over owned elements in row-major order.
-    pub fn into_iter_all(self) -> impl Iterator<Item = T> {
-        self.into_iter().flat_map(IntoIterator::into_iter)
+    #[inline(always)]
+    pub fn into_iter_all(self) -> vec::IntoIter<T> {
+        self.elems.into_iter()
     }
 
     /// Iterator over shared references to elements in row-major order.
-    pub fn iter_all(&self) -> impl Iterator<Item = &T> {
-        self.into_iter().flat_map(IntoIterator::into_iter)
+    #[inline(always)]
+    pub fn iter_all(&self) -> slice::Iter<'_, T> {
+        self.elems.iter()
     }
 
     /// Iterator over mutable references to elements in row-major order.
-    pub fn iter_mut_all(&mut self) -> impl Iterator<Item = &mut T> {
-        self.into_iter().flat_map(IntoIterator::into_iter)
+    #[inline(always)]
+    pub fn iter_mut_all(&mut self) -> slice::IterMut<'_, T> {
+        self.elems.iter_mut()
     }
 
     /// Iterator over positions in the matrix in row-major order.
@@ -50,7 +53,7 @@ impl<T> Matrix<T> {
         Positions::new(self.get_dim()).zip(self.iter_all())
     }
 
-    /// Iterator over get_dim()utable references to elements and their positions in row-major order.
+    /// Iterator over mutable references to elements and their positions in row-major order.
     pub fn enumerate_mut<P: Pos>(&mut self) -> impl Iterator<Item = (Vec2<P>, &mut T)> {
         Positions::new(self.get_dim()).zip(self.iter_mut_all())
     }
@@ -59,54 +62,48 @@ impl<T> Matrix<T> {
 impl<T> Row<T> {
     /// Iterator over shared references to elements in the row.
     #[inline(always)]
-    pub fn iter(&self) -> slice::Iter<T> {
+    pub fn iter(&self) -> slice::Iter<'_, T> {
         self.into_iter()
     }
 
     /// Iterator over mutable references to elements in the row.
     #[inline(always)]
-    pub fn iter_mut(&mut self) -> slice::IterMut<T> {
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
         self.into_iter()
     }
 }
 
 impl<T> IntoIterator for Matrix<T> {
-    type Item = Row<T>;
-    type IntoIter = vec::IntoIter<Row<T>>;
+    type Item = Vec<T>;
+    type IntoIter = IntoRows<T>;
 
     #[inline(always)]
     fn into_iter(self) -> Self::IntoIter {
-        self.rows.into_iter()
+        IntoRows {
+            width: self.get_width(),
+            elems: self.elems.into_iter(),
+        }
     }
 }
 
 impl<'a, T> IntoIterator for &'a Matrix<T> {
     type Item = &'a Row<T>;
-    type IntoIter = slice::Iter<'a, Row<T>>;
+    type IntoIter = Rows<'a, T>;
 
     #[inline(always)]
     fn into_iter(self) -> Self::IntoIter {
-        self.rows.iter()
+        Rows(self.elems.chunks_exact(self.get_width()))
     }
 }
 
 impl<'a, T> IntoIterator for &'a mut Matrix<T> {
     type Item = &'a mut Row<T>;
-    type IntoIter = slice::IterMut<'a, Row<T>>;
+    type IntoIter = RowsMut<'a, T>;
 
     #[inline(always)]
     fn into_iter(self) -> Self::IntoIter {
-        self.rows.iter_mut()
-    }
-}
-
-impl<T> IntoIterator for Row<T> {
-    type Item = T;
-    type IntoIter = vec::IntoIter<T>;
-
-    #[inline(always)]
-    fn into_iter(self) -> Self::IntoIter {
-        self.elems.into_iter()
+        let width = self.get_width();
+        RowsMut(self.elems.chunks_exact_mut(width))
     }
 }
 
@@ -130,6 +127,102 @@ impl<'a, T> IntoIterator for &'a mut Row<T> {
     }
 }
 
+/// Iterator over shared references to rows in a matrix.
+#[derive(Debug, Clone)]
+pub struct Rows<'a, T>(slice::ChunksExact<'a, T>);
+
+impl<'a, T> Iterator for Rows<'a, T> {
+    type Item = &'a Row<T>;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(Row::new)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+
+    #[inline(always)]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.0.nth(n).map(Row::new)
+    }
+}
+
+impl<T> DoubleEndedIterator for Rows<'_, T> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(Row::new)
+    }
+}
+
+impl<T> ExactSizeIterator for Rows<'_, T> {}
+
+impl<T> FusedIterator for Rows<'_, T> {}
+
+/// Iterator over mutable references to rows in a matrix.
+#[derive(Debug)]
+pub struct RowsMut<'a, T>(slice::ChunksExactMut<'a, T>);
+
+impl<'a, T> Iterator for RowsMut<'a, T> {
+    type Item = &'a mut Row<T>;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(Row::new_mut)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+
+    #[inline(always)]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.0.nth(n).map(Row::new_mut)
+    }
+}
+
+impl<T> DoubleEndedIterator for RowsMut<'_, T> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(Row::new_mut)
+    }
+}
+
+impl<T> ExactSizeIterator for RowsMut<'_, T> {}
+
+impl<T> FusedIterator for RowsMut<'_, T> {}
+
+/// Iterator over owned rows in a matrix.
+#[derive(Debug, Clone)]
+pub struct IntoRows<T> {
+    elems: vec::IntoIter<T>,
+    width: usize,
+}
+
+impl<T> Iterator for IntoRows<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.elems.len() == 0 {
+            return None;
+        }
+        Some(self.elems.by_ref().take(self.width).collect())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.elems.len() / self.width;
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for IntoRows<T> {}
+
+impl<T> FusedIterator for IntoRows<T> {}
+
 /// Iterator over positions in a matrix in row-major order.
 pub struct Positions<I> {
     pos: Vec2<usize>,
@@ -139,7 +232,7 @@ pub struct Positions<I> {
 
 impl<I> Positions<I> {
     #[inline]
-    fn new(dim: Vec2<usize>) -> Self {
+    pub(crate) fn new(dim: Vec2<usize>) -> Self {
         Self {
             pos: v(0, 0),
             dim,
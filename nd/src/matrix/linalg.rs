@@ -1,13 +1,13 @@
 use std::{
     mem,
-    ops::{AddAssign, Mul},
+    ops::{AddAssign, Mul, Neg, RemAssign},
 };
 
-use num_traits::{Signed, Zero};
+use num_traits::{real::Real, One, Signed, Zero};
 
 use crate::{
     matrix::{Matrix, Row},
-    traits::{Field, FieldOps},
+    traits::{Field, FieldOps, Pivot},
     vector::v,
     Vec2,
 };
@@ -31,35 +31,38 @@ impl<T> Matrix<T> {
                 other.get_height()
             );
         }
-        for (row, mut add) in self.iter_mut().zip(other) {
-            row.elems.append(&mut add.elems);
+        let (lhs_width, rhs_width) = (self.get_width(), other.get_width());
+        let dim = v(lhs_width + rhs_width, self.get_height());
+        let mut elems = Vec::with_capacity(dim.x * dim.y);
+        let mut lhs = mem::take(&mut self.elems).into_iter();
+        let mut rhs = other.elems.into_iter();
+        for _ in 0..dim.y {
+            elems.extend(lhs.by_ref().take(lhs_width));
+            elems.extend(rhs.by_ref().take(rhs_width));
         }
+        *self = Self { elems, dim };
     }
 
     /// Returns the transpose of `self`.
-    pub fn transpose(self) -> Self {
+    pub fn transpose(mut self) -> Self {
         let dim = self.get_dim();
-        let dim = v(dim.y, dim.x);
-        let mut rows = Vec::with_capacity(dim.y);
-        for _ in 0..dim.y {
-            rows.push(Row::new(Vec::with_capacity(dim.x)));
-        }
-        for row in self.rows {
-            for (i, e) in row.into_iter().enumerate() {
-                rows[i].elems.push(e);
-            }
-        }
-        Self { rows }
+        self.permute(v(dim.y, dim.x), |i| (i % dim.y) * dim.x + i / dim.y);
+        self
     }
 
     fn rem_lhs(&mut self, len: usize) {
-        for row in self.iter_mut() {
-            row.elems.drain(..len);
-        }
+        let width = self.get_width();
+        let mut i = 0;
+        self.elems.retain(|_| {
+            let keep = i % width >= len;
+            i += 1;
+            keep
+        });
+        self.dim.x -= len;
     }
 
     #[track_caller]
-    fn assert_sq(&self) {
+    pub(super) fn assert_sq(&self) {
         if !self.is_square() {
             panic!(
                 "matrix is not square ({}x{})",
@@ -70,16 +73,17 @@ impl<T> Matrix<T> {
     }
 }
 
-impl<T: Field + Signed + PartialOrd> Matrix<T>
+impl<T: Pivot> Matrix<T>
 where
     for<'a> &'a T: FieldOps<T>,
 {
     /// Returns the determinant of `self`.
     ///
+    /// For integer types, use [`Matrix::det_int`] instead.
+    ///
     /// Panics if `self` is not square.
     #[track_caller]
     pub fn det(&self) -> T {
-        // TODO: allow this to work with integers
         self.assert_sq();
         self.clone().row_ef_det()
     }
@@ -95,14 +99,12 @@ where
     fn row_ef_det(&mut self) -> T {
         let dim = self.get_dim();
         let mut det = T::one();
-        let mut pivot = Vec2::zero();
+        let mut pivot: Vec2<usize> = Vec2::zero();
         while self.in_bounds(pivot) {
-            let (mut max_row, mut max_abs_value) = (pivot.y, T::zero());
-            for row in pivot.y..dim.y {
-                let abs_value = self[row][pivot.x].abs();
-                if abs_value > max_abs_value {
+            let mut max_row = pivot.y;
+            for row in pivot.y + 1..dim.y {
+                if self[row][pivot.x].better_pivot(&self[max_row][pivot.x]) {
                     max_row = row;
-                    max_abs_value = abs_value;
                 }
             }
             let max_value = &self[max_row][pivot.x];
@@ -110,8 +112,10 @@ where
                 pivot.x += 1;
             } else {
                 let pivot_coeff = max_value.clone();
-                self.rows.swap(pivot.y, max_row);
-                det = -det;
+                if max_row != pivot.y {
+                    self.swap_rows(pivot.y, max_row);
+                    det = -det;
+                }
                 for row in pivot.y + 1..dim.y {
                     let coeff = &self[row][pivot.x];
                     if !coeff.is_zero() {
@@ -172,6 +176,48 @@ where
         Some(self)
     }
 
+    /// Returns a particular solution to the equation `self * x = rhs` along with a basis for the nullspace of `self`,
+    /// or `None` if there is no solution.
+    ///
+    /// Every solution is the particular solution plus a linear combination of the basis vectors,
+    /// which are returned as column vectors. Free variables are set to `0` in the particular solution.
+    ///
+    /// Panics if `self` and `rhs` do not have the same height.
+    #[track_caller]
+    pub fn solve_general(mut self, rhs: Self) -> Option<(Self, Vec<Self>)> {
+        let width = self.get_width();
+        let rhs_width = rhs.get_width();
+        self.augment(rhs);
+        self.rref();
+        let pivots = self.pivots();
+        if pivots.last().is_some_and(|&col| col >= width) {
+            return None;
+        }
+        let mut particular = Self::init(v(rhs_width, width), T::zero());
+        for (row, &col) in pivots.iter().enumerate() {
+            for c in 0..rhs_width {
+                particular[col][c] = self[row][width + c].clone();
+            }
+        }
+        let nullspace = self.nullspace_basis(width, &pivots);
+        Some((particular, nullspace))
+    }
+
+    /// Returns the rank of `self`.
+    pub fn rank(&self) -> usize {
+        let mut mat = self.clone();
+        mat.row_ef();
+        mat.pivots().len()
+    }
+
+    /// Returns a basis for the nullspace of `self` as column vectors, i.e. vectors `x` such that `self * x = 0`.
+    pub fn nullspace(&self) -> Vec<Self> {
+        let mut mat = self.clone();
+        mat.rref();
+        let pivots = mat.pivots();
+        mat.nullspace_basis(self.get_width(), &pivots)
+    }
+
     /// Returns the inverse of `self`, or `None` if `self` is singular.
     ///
     /// Panics if `self` is not square.
@@ -188,20 +234,233 @@ where
     }
 
     /// Returns the matrix of cofactors of `self`.
-    #[allow(unused)]
+    ///
+    /// Panics if `self` is not square.
     #[track_caller]
-    pub fn cofactor(mut self) -> Self {
-        todo!()
+    pub fn cofactor(self) -> Self {
+        self.assert_sq();
+        let size = self.get_width();
+        if size == 1 {
+            return Self::id(1);
+        }
+        let mut elems = Vec::with_capacity(size * size);
+        for r in 0..size {
+            for c in 0..size {
+                let det = self.minor(r, c).det();
+                elems.push(if (r + c) % 2 == 0 { det } else { -det });
+            }
+        }
+        Self::from_flat(v(size, size), elems)
     }
 
     /// Returns `self` raised to the given power.
     ///
+    /// Negative exponents raise the inverse of `self` to the corresponding positive power.
+    ///
+    /// Panics if `self` is not square, or if the exponent is negative and `self` is singular.
+    #[track_caller]
+    pub fn pow(self, exponent: i32) -> Self {
+        self.assert_sq();
+        let base = if exponent < 0 {
+            match self.inverse() {
+                Some(inverse) => inverse,
+                None => panic!("matrix is singular"),
+            }
+        } else {
+            self
+        };
+        base.pow_by_squaring(exponent.unsigned_abs() as u64, |_| {})
+    }
+
+    fn minor(&self, row: usize, col: usize) -> Self {
+        let dim = self.get_dim();
+        let elems = self
+            .enumerate::<usize>()
+            .filter(|(pos, _)| pos.x != col && pos.y != row)
+            .map(|(_, e)| e.clone());
+        Self::from_flat(v(dim.x - 1, dim.y - 1), elems)
+    }
+}
+
+impl<T: Pivot + Real> Matrix<T>
+where
+    for<'a> &'a T: FieldOps<T>,
+{
+    /// Returns the least-squares solution to the equation `self * x = rhs`,
+    /// or `None` if the columns of `self` are not linearly independent.
+    ///
+    /// Panics if `self` and `rhs` do not have the same height.
+    #[track_caller]
+    pub fn least_squares(self, rhs: Self) -> Option<Self> {
+        let transpose = self.clone().transpose();
+        let lhs = &transpose * &self;
+        let rhs = &transpose * &rhs;
+        lhs.solve(rhs)
+    }
+}
+
+impl<T: Field + Signed> Matrix<T>
+where
+    for<'a> &'a T: FieldOps<T>,
+{
+    /// Returns the determinant of `self`, using fraction-free elimination.
+    ///
+    /// Unlike [`Matrix::det`], every division is exact, so this is suitable for integer types.
+    ///
+    /// Panics if `self` is not square.
+    #[track_caller]
+    pub fn det_int(&self) -> T {
+        self.assert_sq();
+        let size = self.get_width();
+        let mut mat = self.clone();
+        let (rank, negate) = mat.bareiss(size);
+        if rank < size {
+            return T::zero();
+        }
+        let det = mem::replace(&mut mat[size - 1][size - 1], T::zero());
+        if negate {
+            -det
+        } else {
+            det
+        }
+    }
+
+    /// Returns the rank of `self`, using fraction-free elimination.
+    pub fn rank_int(&self) -> usize {
+        self.clone().bareiss(self.get_width()).0
+    }
+
+    /// Transforms `self` into row echelon form using fraction-free elimination.
+    ///
+    /// Every element remains exact for integer types, and the last pivot is equal
+    /// to the determinant of the leading square block up to sign.
+    #[inline(always)]
+    pub fn row_ef_int(&mut self) {
+        self.bareiss(self.get_width());
+    }
+
+    /// Returns the solution to the equation `self * x = rhs` as a matrix of numerators over a common
+    /// positive denominator, or `None` if `self` is singular.
+    ///
+    /// The denominator is the absolute value of the determinant of `self`, and is not reduced.
+    ///
+    /// Panics if `self` is not square, or if `self` and `rhs` do not have the same height.
+    #[track_caller]
+    pub fn solve_frac(mut self, rhs: Self) -> Option<(Self, T)> {
+        self.assert_sq();
+        let size = self.get_width();
+        self.augment(rhs);
+        let (rank, _) = self.bareiss(size);
+        if rank < size {
+            return None;
+        }
+        let width = self.get_width();
+        let mut denom = self[size - 1][size - 1].clone();
+        if denom.is_negative() {
+            self *= &-T::one();
+            denom = -denom;
+        }
+        for col in size..width {
+            for row in (0..size).rev() {
+                let mut numer = &denom * &self[row][col];
+                for k in row + 1..size {
+                    numer -= &self[row][k] * &self[k][col];
+                }
+                numer /= &self[row][row];
+                self[row][col] = numer;
+            }
+        }
+        self.rem_lhs(size);
+        Some((self, denom))
+    }
+
+    /// Returns the solution to the equation `self * x = rhs`, or `None` if `self` is singular
+    /// or the solution is not integral.
+    ///
+    /// Panics if `self` is not square, or if `self` and `rhs` do not have the same height.
+    #[track_caller]
+    pub fn solve_int(self, rhs: Self) -> Option<Self> {
+        let (mut numer, denom) = self.solve_frac(rhs)?;
+        if numer.iter_all().any(|e| !(e % &denom).is_zero()) {
+            return None;
+        }
+        numer /= &denom;
+        Some(numer)
+    }
+
+    /// Performs Bareiss elimination on the first `cols` columns of `self`,
+    /// returning the rank of those columns and whether the row swaps negated the determinant.
+    fn bareiss(&mut self, cols: usize) -> (usize, bool) {
+        let dim = self.get_dim();
+        let mut negate = false;
+        let mut prev = T::one();
+        let mut pivot = Vec2::zero();
+        while pivot.x < cols && pivot.y < dim.y {
+            let Some(pivot_row) = (pivot.y..dim.y).find(|&row| !self[row][pivot.x].is_zero())
+            else {
+                pivot.x += 1;
+                continue;
+            };
+            if pivot_row != pivot.y {
+                self.swap_rows(pivot.y, pivot_row);
+                negate = !negate;
+            }
+            let pivot_coeff = self[pivot.y][pivot.x].clone();
+            for row in pivot.y + 1..dim.y {
+                let coeff = mem::replace(&mut self[row][pivot.x], T::zero());
+                for col in pivot.x + 1..dim.x {
+                    let mut value = &pivot_coeff * &self[row][col];
+                    value -= &coeff * &self[pivot.y][col];
+                    value /= &prev;
+                    self[row][col] = value;
+                }
+            }
+            prev = pivot_coeff;
+            pivot += v(1, 1);
+        }
+        (pivot.y, negate)
+    }
+}
+
+impl<T: Clone + Zero + One + AddAssign> Matrix<T>
+where
+    for<'a> &'a T: Mul<&'a T, Output = T>,
+    for<'a> T: RemAssign<&'a T>,
+{
+    /// Returns `self` raised to the given power, with every element reduced modulo `modulus`.
+    ///
+    /// Elements are reduced after every multiplication, so `T` only needs to hold the square of the modulus
+    /// multiplied by the width of the matrix.
+    ///
     /// Panics if `self` is not square.
-    #[allow(unused)]
     #[track_caller]
-    pub fn pow(mut self, exponent: i32) -> Self {
+    pub fn pow_mod(mut self, exponent: u64, modulus: &T) -> Self {
         self.assert_sq();
-        todo!()
+        self %= modulus;
+        self.pow_by_squaring(exponent, |mat| *mat %= modulus)
+    }
+}
+
+impl<T: Clone + Zero + One + AddAssign> Matrix<T>
+where
+    for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+    fn pow_by_squaring(self, mut exponent: u64, mut reduce: impl FnMut(&mut Self)) -> Self {
+        let mut result = Self::id(self.get_width());
+        reduce(&mut result);
+        let mut base = self;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = &result * &base;
+                reduce(&mut result);
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = &base * &base;
+                reduce(&mut base);
+            }
+        }
+        result
     }
 }
 
@@ -223,9 +482,8 @@ where
         }
         let len = dim_l.x;
         let dim = v(dim_r.x, dim_l.y);
-        let mut rows = Vec::with_capacity(dim.y);
+        let mut elems = Vec::with_capacity(dim.x * dim.y);
         for r in 0..dim.y {
-            let mut elems = Vec::with_capacity(dim.x);
             for c in 0..dim.x {
                 let mut total = &self[r][0] * &rhs[0][c];
                 for k in 1..len {
@@ -233,9 +491,35 @@ where
                 }
                 elems.push(total);
             }
-            rows.push(Row::new(elems));
         }
-        Matrix { rows }
+        Matrix { elems, dim }
+    }
+}
+
+impl<T: Clone + Zero + One + Neg<Output = T>> Matrix<T> {
+    /// Returns the column of the leading coefficient of each nonzero row, assuming `self` is in row echelon form.
+    fn pivots(&self) -> Vec<usize> {
+        self.iter().map_while(Row::leading_coeff).collect()
+    }
+
+    /// Returns a basis for the nullspace of the first `cols` columns of `self` as column vectors,
+    /// assuming `self` is in reduced row echelon form with the given pivot columns.
+    fn nullspace_basis(&self, cols: usize, pivots: &[usize]) -> Vec<Self> {
+        let mut is_pivot = vec![false; cols];
+        for &col in pivots {
+            is_pivot[col] = true;
+        }
+        (0..cols)
+            .filter(|&col| !is_pivot[col])
+            .map(|free| {
+                let mut x = Self::init(v(1, cols), T::zero());
+                x[free][0] = T::one();
+                for (row, &col) in pivots.iter().enumerate() {
+                    x[col][0] = -self[row][free].clone();
+                }
+                x
+            })
+            .collect()
     }
 }
 
@@ -10,18 +10,12 @@ use crate::{
 };
 
 impl<T> Matrix<T> {
-    #[track_caller]
-    fn new_unchecked(&self, rows_iter: impl Iterator<Item = impl Iterator<Item = T>>) -> Self {
-        let dim = self.get_dim();
-        let mut rows = Vec::with_capacity(dim.y);
-        for row in rows_iter {
-            let mut elems = Vec::with_capacity(dim.x);
-            for e in row {
-                elems.push(e);
-            }
-            rows.push(Row::new(elems));
+    #[inline(always)]
+    fn new_unchecked(&self, elems: impl Iterator<Item = T>) -> Self {
+        Self {
+            elems: elems.collect(),
+            dim: self.get_dim(),
         }
-        Self { rows }
     }
 
     #[track_caller]
@@ -87,7 +81,7 @@ impl<T, P: Pos> Index<Vec2<P>> for Matrix<T> {
     #[track_caller]
     fn index(&self, index: Vec2<P>) -> &Self::Output {
         let pos = self.pos_in_bounds(index);
-        &self.rows[pos.y].elems[pos.x]
+        &self.elems[self.flat_index(pos)]
     }
 }
 
@@ -95,7 +89,8 @@ impl<T, P: Pos> IndexMut<Vec2<P>> for Matrix<T> {
     #[track_caller]
     fn index_mut(&mut self, index: Vec2<P>) -> &mut Self::Output {
         let pos = self.pos_in_bounds(index);
-        &mut self.rows[pos.y].elems[pos.x]
+        let i = self.flat_index(pos);
+        &mut self.elems[i]
     }
 }
 
@@ -105,7 +100,7 @@ impl<T, P: Pos> Index<P> for Matrix<T> {
     #[track_caller]
     fn index(&self, index: P) -> &Self::Output {
         let row = self.row_in_bounds(index);
-        &self.rows[row]
+        Row::new(self.row_slice(row))
     }
 }
 
@@ -113,7 +108,7 @@ impl<T, P: Pos> IndexMut<P> for Matrix<T> {
     #[track_caller]
     fn index_mut(&mut self, index: P) -> &mut Self::Output {
         let row = self.row_in_bounds(index);
-        &mut self.rows[row]
+        Row::new_mut(self.row_slice_mut(row))
     }
 }
 
@@ -145,11 +140,7 @@ macro_rules! impl_term {
             #[track_caller]
             fn $f(self, rhs: &'b Matrix<T>) -> Self::Output {
                 self.assert_dim_eq(rhs);
-                self.new_unchecked(
-                    self.iter()
-                        .zip(rhs)
-                        .map(|(a, b)| a.iter().zip(b).map(|(a, b)| a $op b)),
-                )
+                self.new_unchecked(self.iter_all().zip(rhs.iter_all()).map(|(a, b)| a $op b))
             }
         }
 
@@ -157,10 +148,8 @@ macro_rules! impl_term {
             #[track_caller]
             fn $f_a(&mut self, rhs: &'a Matrix<T>) {
                 self.assert_dim_eq(rhs);
-                for (a, b) in self.iter_mut().zip(rhs) {
-                    for (a, b) in a.iter_mut().zip(b) {
-                        *a $op_a b;
-                    }
+                for (a, b) in self.iter_mut_all().zip(rhs.iter_all()) {
+                    *a $op_a b;
                 }
             }
         }
@@ -180,17 +169,15 @@ macro_rules! impl_factor {
 
             #[track_caller]
             fn $f(self, rhs: &'b T) -> Self::Output {
-                self.new_unchecked(self.iter().map(|r| r.iter().map(|e| e $op rhs)))
+                self.new_unchecked(self.iter_all().map(|e| e $op rhs))
             }
         }
 
         impl<'a, T: $tr_a<&'a T>> $tr_a<&'a T> for Matrix<T> {
             #[track_caller]
             fn $f_a(&mut self, rhs: &'a T) {
-                for row in self {
-                    for e in row {
-                        *e $op_a rhs;
-                    }
+                for e in self.iter_mut_all() {
+                    *e $op_a rhs;
                 }
             }
         }
@@ -200,17 +187,15 @@ macro_rules! impl_factor {
 
             #[track_caller]
             fn $f(self, rhs: T) -> Self::Output {
-                self.new_unchecked(self.iter().map(|r| r.iter().map(|e| *e $op rhs)))
+                self.new_unchecked(self.iter_all().map(|e| *e $op rhs))
             }
         }
 
         impl<T: Copy + $tr_a> $tr_a<T> for Matrix<T> {
             #[track_caller]
             fn $f_a(&mut self, rhs: T) {
-                for row in self {
-                    for e in row {
-                        *e $op_a rhs;
-                    }
+                for e in self.iter_mut_all() {
+                    *e $op_a rhs;
                 }
             }
         }
@@ -229,6 +214,6 @@ where
 
     #[track_caller]
     fn neg(self) -> Self::Output {
-        self.new_unchecked(self.iter().map(|r| r.iter().map(|e| -e)))
+        self.new_unchecked(self.iter_all().map(|e| -e))
     }
 }
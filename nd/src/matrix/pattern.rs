@@ -0,0 +1,103 @@
+use crate::{
+    matrix::{Matrix, Positions},
+    traits::Pos,
+    vector::{v, Vec2},
+};
+
+impl<T> Matrix<T> {
+    /// Iterator over the positions at which the top-left corner of `pattern` can be placed
+    /// so that every element of `pattern` equals the element of `self` beneath it.
+    ///
+    /// Elements of `pattern` equal to `wildcard` match any element.
+    ///
+    /// Panics if a position cannot be converted to the type `P`.
+    pub fn find_pattern<'a, P: Pos>(
+        &'a self,
+        pattern: &'a Matrix<T>,
+        wildcard: Option<&'a T>,
+    ) -> impl Iterator<Item = Vec2<P>> + 'a
+    where
+        T: PartialEq,
+    {
+        let dim = self.get_dim();
+        let pattern_dim = pattern.get_dim();
+        let range = v(
+            (dim.x + 1).saturating_sub(pattern_dim.x),
+            (dim.y + 1).saturating_sub(pattern_dim.y),
+        );
+        Positions::<usize>::new(range)
+            .filter(move |&pos| self.matches_at(pos, pattern, wildcard))
+            .map(|pos| pos.pos("position", "type P"))
+    }
+
+    /// Returns each distinct orientation of `pattern` that occurs in `self`,
+    /// along with the positions at which it occurs, as for [`Matrix::find_pattern`].
+    ///
+    /// Orientations are taken in the order of [`Matrix::orientations`],
+    /// and those equal to an earlier orientation are skipped.
+    ///
+    /// Panics if a position cannot be converted to the type `P`.
+    pub fn find_pattern_oriented<P: Pos>(
+        &self,
+        pattern: &Matrix<T>,
+        wildcard: Option<&T>,
+    ) -> Vec<(Matrix<T>, Vec<Vec2<P>>)>
+    where
+        T: Clone + PartialEq,
+    {
+        let mut seen = Vec::new();
+        let mut found = Vec::new();
+        for pattern in pattern.orientations() {
+            if seen.contains(&pattern) {
+                continue;
+            }
+            let positions: Vec<_> = self.find_pattern(&pattern, wildcard).collect();
+            if !positions.is_empty() {
+                found.push((pattern.clone(), positions));
+            }
+            seen.push(pattern);
+        }
+        found
+    }
+
+    /// Returns a new matrix containing a copy of the sub-matrix with its top-left corner at `pos`
+    /// and the given dimensions.
+    ///
+    /// Panics if the sub-matrix would be empty or would not fit inside the matrix.
+    #[track_caller]
+    pub fn sub_matrix<P: Pos>(&self, pos: Vec2<P>, dim: Vec2<P>) -> Self
+    where
+        T: Clone,
+    {
+        self.window(pos, dim).to_matrix()
+    }
+
+    /// Overwrites the elements of `self` with copies of the elements of `other`,
+    /// with the top-left corner of `other` placed at `pos`.
+    ///
+    /// Panics if `other` would not fit inside the matrix.
+    #[track_caller]
+    pub fn paste<P: Pos>(&mut self, pos: Vec2<P>, other: &Matrix<T>)
+    where
+        T: Clone,
+    {
+        let pos = pos.index("pos");
+        let mut window = self.window_mut(pos, other.get_dim());
+        for (dst, src) in window.iter_mut_all().zip(other.iter_all()) {
+            dst.clone_from(src);
+        }
+    }
+
+    fn matches_at(&self, pos: Vec2<usize>, pattern: &Matrix<T>, wildcard: Option<&T>) -> bool
+    where
+        T: PartialEq,
+    {
+        let width = pattern.get_width();
+        pattern.iter().enumerate().all(|(y, row)| {
+            let start = self.flat_index(v(pos.x, pos.y + y));
+            row.iter()
+                .zip(&self.elems[start..start + width])
+                .all(|(p, e)| Some(p) == wildcard || p == e)
+        })
+    }
+}
@@ -0,0 +1,106 @@
+use std::iter::FusedIterator;
+
+use crate::{matrix::Matrix, vector::v};
+
+impl<T> Matrix<T> {
+    /// Rotates `self` a quarter turn clockwise.
+    pub fn rotate_cw(&mut self) {
+        let dim = self.get_dim();
+        self.permute(v(dim.y, dim.x), |i| {
+            (dim.y - 1 - i % dim.y) * dim.x + i / dim.y
+        });
+    }
+
+    /// Rotates `self` a quarter turn anticlockwise.
+    pub fn rotate_ccw(&mut self) {
+        let dim = self.get_dim();
+        self.permute(v(dim.y, dim.x), |i| {
+            (i % dim.y) * dim.x + dim.x - 1 - i / dim.y
+        });
+    }
+
+    /// Rotates `self` a half turn.
+    pub fn rotate_180(&mut self) {
+        self.elems.reverse();
+    }
+
+    /// Flips `self` horizontally, reversing the order of elements in each row.
+    pub fn flip_h(&mut self) {
+        for row in self.iter_mut() {
+            row.as_mut_slice().reverse();
+        }
+    }
+
+    /// Flips `self` vertically, reversing the order of the rows.
+    pub fn flip_v(&mut self) {
+        let height = self.get_height();
+        for row in 0..height / 2 {
+            self.swap_rows(row, height - 1 - row);
+        }
+    }
+
+    /// Transposes `self` in place.
+    ///
+    /// Panics if `self` is not square.
+    #[track_caller]
+    pub fn transpose_in_place(&mut self) {
+        self.assert_sq();
+        let n = self.get_width();
+        for y in 0..n {
+            for x in y + 1..n {
+                self.elems.swap(y * n + x, x * n + y);
+            }
+        }
+    }
+
+    /// Iterator over the 8 orientations of `self` under rotation and reflection.
+    ///
+    /// The first 4 are the rotations of `self` by successive clockwise quarter turns,
+    /// and the last 4 are the rotations of its reflection.
+    pub fn orientations(&self) -> Orientations<T>
+    where
+        T: Clone,
+    {
+        Orientations {
+            matrix: Some(self.clone()),
+            index: 0,
+        }
+    }
+}
+
+/// Iterator over the 8 orientations of a matrix under rotation and reflection.
+#[derive(Debug, Clone)]
+pub struct Orientations<T> {
+    matrix: Option<Matrix<T>>,
+    index: u8,
+}
+
+impl<T: Clone> Iterator for Orientations<T> {
+    type Item = Matrix<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == 7 {
+            self.index += 1;
+            return self.matrix.take();
+        }
+        let matrix = self.matrix.as_mut()?;
+        let current = matrix.clone();
+        if self.index == 3 {
+            matrix.flip_h();
+        } else {
+            matrix.rotate_cw();
+        }
+        self.index += 1;
+        Some(current)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = 8 - self.index as usize;
+        (len, Some(len))
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for Orientations<T> {}
+
+impl<T: Clone> FusedIterator for Orientations<T> {}
@@ -0,0 +1,550 @@
+use std::{
+    iter::{FusedIterator, StepBy, Take},
+    ops::{Index, IndexMut},
+    slice,
+};
+
+use crate::{
+    matrix::{iter::Positions, Matrix},
+    traits::Pos,
+    vector::{v, Vec2},
+};
+
+impl<T> Matrix<T> {
+    /// Returns a view of the given column.
+    ///
+    /// Panics if the column index is out of bounds.
+    #[track_caller]
+    pub fn column<P: Pos>(&self, col: P) -> ColView<'_, T> {
+        let col = self.col_in_bounds(col);
+        ColView {
+            elems: &self.elems,
+            base: col,
+            stride: self.get_width(),
+            len: self.get_height(),
+        }
+    }
+
+    /// Returns a mutable view of the given column.
+    ///
+    /// Panics if the column index is out of bounds.
+    #[track_caller]
+    pub fn column_mut<P: Pos>(&mut self, col: P) -> ColViewMut<'_, T> {
+        let col = self.col_in_bounds(col);
+        let (stride, len) = (self.get_width(), self.get_height());
+        ColViewMut {
+            elems: &mut self.elems,
+            base: col,
+            stride,
+            len,
+        }
+    }
+
+    /// Iterator over views of the columns in the matrix.
+    #[inline]
+    pub fn columns(&self) -> Cols<'_, T> {
+        Cols {
+            mat: self,
+            start: 0,
+            end: self.get_width(),
+        }
+    }
+
+    /// Returns a view of the whole matrix.
+    #[inline]
+    pub fn view(&self) -> View<'_, T> {
+        View {
+            elems: &self.elems,
+            base: 0,
+            stride: v(1, self.get_width()),
+            dim: self.get_dim(),
+        }
+    }
+
+    /// Returns a mutable view of the whole matrix.
+    #[inline]
+    pub fn view_mut(&mut self) -> ViewMut<'_, T> {
+        let (stride, dim) = (v(1, self.get_width()), self.get_dim());
+        ViewMut {
+            elems: &mut self.elems,
+            base: 0,
+            stride,
+            dim,
+        }
+    }
+
+    /// Returns a view of the sub-matrix with its top-left corner at `pos` and the given dimensions.
+    ///
+    /// Panics if the window would be empty or would not fit inside the matrix.
+    #[track_caller]
+    pub fn window<P: Pos>(&self, pos: Vec2<P>, dim: Vec2<P>) -> View<'_, T> {
+        self.view().window(pos, dim)
+    }
+
+    /// Returns a mutable view of the sub-matrix with its top-left corner at `pos` and the given dimensions.
+    ///
+    /// Panics if the window would be empty or would not fit inside the matrix.
+    #[track_caller]
+    pub fn window_mut<P: Pos>(&mut self, pos: Vec2<P>, dim: Vec2<P>) -> ViewMut<'_, T> {
+        self.view_mut().window(pos, dim)
+    }
+
+    /// Returns a view of every `step.x`th column and `step.y`th row, starting from the top-left corner.
+    ///
+    /// Panics if either component of `step` is zero.
+    #[track_caller]
+    pub fn strided<P: Pos>(&self, step: Vec2<P>) -> View<'_, T> {
+        self.view().strided(step)
+    }
+
+    /// Returns a mutable view of every `step.x`th column and `step.y`th row, starting from the top-left corner.
+    ///
+    /// Panics if either component of `step` is zero.
+    #[track_caller]
+    pub fn strided_mut<P: Pos>(&mut self, step: Vec2<P>) -> ViewMut<'_, T> {
+        self.view_mut().strided(step)
+    }
+
+    #[track_caller]
+    fn col_in_bounds<P: Pos>(&self, col: P) -> usize {
+        if let Some(i) = col
+            .get_index()
+            .and_then(|i| (i < self.get_width()).then_some(i))
+        {
+            i
+        } else {
+            panic!(
+                "column index out of bounds: width is {} but column index is {col:?}",
+                self.get_width()
+            );
+        }
+    }
+}
+
+/// A view of a single column of a matrix.
+#[derive(Debug)]
+pub struct ColView<'a, T> {
+    elems: &'a [T],
+    base: usize,
+    stride: usize,
+    len: usize,
+}
+
+/// A mutable view of a single column of a matrix.
+#[derive(Debug)]
+pub struct ColViewMut<'a, T> {
+    elems: &'a mut [T],
+    base: usize,
+    stride: usize,
+    len: usize,
+}
+
+impl<T> Clone for ColView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ColView<'_, T> {}
+
+macro_rules! impl_col_view {
+    ($view:ident) => {
+        impl<T> $view<'_, T> {
+            /// Returns the number of elements in the column.
+            #[inline(always)]
+            #[allow(clippy::len_without_is_empty)]
+            pub fn len(&self) -> usize {
+                self.len
+            }
+
+            /// Returns a shared reference to the element at the given index, or `None` if the index is out of bounds.
+            pub fn get<P: Pos>(&self, index: P) -> Option<&T> {
+                index
+                    .get_index()
+                    .and_then(|i| (i < self.len).then(|| &self.elems[self.base + i * self.stride]))
+            }
+
+            /// Iterator over shared references to elements in the column.
+            pub fn iter(&self) -> Take<StepBy<slice::Iter<'_, T>>> {
+                self.elems[self.base..]
+                    .iter()
+                    .step_by(self.stride)
+                    .take(self.len)
+            }
+
+            #[track_caller]
+            fn index_in_bounds<P: Pos>(&self, index: P) -> usize {
+                if let Some(i) = index.get_index().and_then(|i| (i < self.len).then_some(i)) {
+                    self.base + i * self.stride
+                } else {
+                    panic!(
+                        "row index out of bounds: height is {} but row index is {index:?}",
+                        self.len
+                    );
+                }
+            }
+        }
+
+        impl<T: Clone> $view<'_, T> {
+            /// Returns a copy of the elements in the column.
+            pub fn to_vec(&self) -> Vec<T> {
+                self.iter().cloned().collect()
+            }
+        }
+
+        impl<T, P: Pos> Index<P> for $view<'_, T> {
+            type Output = T;
+
+            #[track_caller]
+            fn index(&self, index: P) -> &Self::Output {
+                &self.elems[self.index_in_bounds(index)]
+            }
+        }
+    };
+}
+
+impl_col_view!(ColView);
+impl_col_view!(ColViewMut);
+
+impl<T> ColViewMut<'_, T> {
+    /// Returns a mutable reference to the element at the given index, or `None` if the index is out of bounds.
+    pub fn get_mut<P: Pos>(&mut self, index: P) -> Option<&mut T> {
+        index
+            .get_index()
+            .and_then(|i| (i < self.len).then(|| &mut self.elems[self.base + i * self.stride]))
+    }
+
+    /// Iterator over mutable references to elements in the column.
+    pub fn iter_mut(&mut self) -> Take<StepBy<slice::IterMut<'_, T>>> {
+        self.elems[self.base..]
+            .iter_mut()
+            .step_by(self.stride)
+            .take(self.len)
+    }
+}
+
+impl<T, P: Pos> IndexMut<P> for ColViewMut<'_, T> {
+    #[track_caller]
+    fn index_mut(&mut self, index: P) -> &mut Self::Output {
+        let i = self.index_in_bounds(index);
+        &mut self.elems[i]
+    }
+}
+
+impl<'a, T> IntoIterator for ColView<'a, T> {
+    type Item = &'a T;
+    type IntoIter = Take<StepBy<slice::Iter<'a, T>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.elems[self.base..]
+            .iter()
+            .step_by(self.stride)
+            .take(self.len)
+    }
+}
+
+impl<'a, T> IntoIterator for ColViewMut<'a, T> {
+    type Item = &'a mut T;
+    type IntoIter = Take<StepBy<slice::IterMut<'a, T>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.elems[self.base..]
+            .iter_mut()
+            .step_by(self.stride)
+            .take(self.len)
+    }
+}
+
+/// Iterator over views of the columns in a matrix.
+#[derive(Debug, Clone)]
+pub struct Cols<'a, T> {
+    mat: &'a Matrix<T>,
+    start: usize,
+    end: usize,
+}
+
+impl<'a, T> Iterator for Cols<'a, T> {
+    type Item = ColView<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        (self.start < self.end).then(|| {
+            self.start += 1;
+            self.mat.column(self.start - 1)
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Cols<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        (self.start < self.end).then(|| {
+            self.end -= 1;
+            self.mat.column(self.end)
+        })
+    }
+}
+
+impl<T> ExactSizeIterator for Cols<'_, T> {}
+
+impl<T> FusedIterator for Cols<'_, T> {}
+
+/// A view of a rectangular, possibly strided, region of a matrix.
+#[derive(Debug)]
+pub struct View<'a, T> {
+    elems: &'a [T],
+    base: usize,
+    stride: Vec2<usize>,
+    dim: Vec2<usize>,
+}
+
+/// A mutable view of a rectangular, possibly strided, region of a matrix.
+#[derive(Debug)]
+pub struct ViewMut<'a, T> {
+    elems: &'a mut [T],
+    base: usize,
+    stride: Vec2<usize>,
+    dim: Vec2<usize>,
+}
+
+impl<T> Clone for View<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for View<'_, T> {}
+
+macro_rules! impl_view {
+    ($view:ident) => {
+        impl<T> $view<'_, T> {
+            /// Returns the dimensions of the view.
+            ///
+            /// Panics if the dimensions cannot be converted to the type `P`.
+            #[track_caller]
+            pub fn dim<P: Pos>(&self) -> Vec2<P> {
+                self.dim.pos("dim", "type P")
+            }
+
+            /// Returns the number of columns in the view.
+            ///
+            /// Panics if the value cannot be converted to the type `P`.
+            #[track_caller]
+            pub fn width<P: Pos>(&self) -> P {
+                P::pos(self.dim.x, "width", "type P")
+            }
+
+            /// Returns the number of rows in the view.
+            ///
+            /// Panics if the value cannot be converted to the type `P`.
+            #[track_caller]
+            pub fn height<P: Pos>(&self) -> P {
+                P::pos(self.dim.y, "height", "type P")
+            }
+
+            /// Returns a shared reference to the element at the given position, or `None` if the position is out of bounds.
+            pub fn get<P: Pos>(&self, pos: Vec2<P>) -> Option<&T> {
+                self.get_in_bounds(pos)
+                    .map(|pos| &self.elems[self.flat_index(pos)])
+            }
+
+            /// Returns whether the given position is within the bounds of the view.
+            pub fn in_bounds<P: Pos>(&self, pos: Vec2<P>) -> bool {
+                self.get_in_bounds(pos).is_some()
+            }
+
+            /// Iterator over shared references to elements in row-major order.
+            pub fn iter_all(&self) -> impl Iterator<Item = &T> {
+                let (stride, dim) = (self.stride, self.dim);
+                self.elems[self.base..]
+                    .chunks(stride.y)
+                    .take(dim.y)
+                    .flat_map(move |row| row.iter().step_by(stride.x).take(dim.x))
+            }
+
+            /// Iterator over positions in the view in row-major order.
+            #[inline]
+            pub fn positions<P: Pos>(&self) -> Positions<P> {
+                Positions::new(self.dim)
+            }
+
+            /// Iterator over shared references to elements and their positions in row-major order.
+            pub fn enumerate<P: Pos>(&self) -> impl Iterator<Item = (Vec2<P>, &T)> {
+                Positions::new(self.dim).zip(self.iter_all())
+            }
+
+            fn get_in_bounds<P: Pos>(&self, pos: Vec2<P>) -> Option<Vec2<usize>> {
+                pos.get_index()
+                    .and_then(|pos| (pos.x < self.dim.x && pos.y < self.dim.y).then_some(pos))
+            }
+
+            #[inline(always)]
+            fn flat_index(&self, pos: Vec2<usize>) -> usize {
+                self.base + pos.x * self.stride.x + pos.y * self.stride.y
+            }
+
+            #[track_caller]
+            fn pos_in_bounds<P: Pos>(&self, pos: Vec2<P>) -> usize {
+                if let Some(i) = self.get_in_bounds(pos.clone()) {
+                    self.flat_index(i)
+                } else {
+                    panic!(
+                        "position out of bounds: dimensions are {} but position is ({:?}, {:?})",
+                        self.dim, pos.x, pos.y
+                    );
+                }
+            }
+
+            #[track_caller]
+            fn window_params<P: Pos>(&self, pos: Vec2<P>, dim: Vec2<P>) -> (usize, Vec2<usize>) {
+                let pos = pos.index("pos");
+                let dim = dim.index("dim");
+                dim.assert_nonempty();
+                if pos.x + dim.x > self.dim.x || pos.y + dim.y > self.dim.y {
+                    panic!(
+                        "window out of bounds: dimensions are {} but window covers {} to {}",
+                        self.dim,
+                        pos,
+                        pos + dim - v(1, 1)
+                    );
+                }
+                (self.flat_index(pos), dim)
+            }
+
+            #[track_caller]
+            fn strided_params<P: Pos>(&self, step: Vec2<P>) -> (Vec2<usize>, Vec2<usize>) {
+                let step = step.index("step");
+                if step.x == 0 || step.y == 0 {
+                    panic!("step has a zero component: {step}");
+                }
+                let stride = v(self.stride.x * step.x, self.stride.y * step.y);
+                let dim = v(self.dim.x.div_ceil(step.x), self.dim.y.div_ceil(step.y));
+                (stride, dim)
+            }
+        }
+
+        impl<T: Clone> $view<'_, T> {
+            /// Returns a new matrix containing a copy of the elements in the view.
+            pub fn to_matrix(&self) -> Matrix<T> {
+                Matrix {
+                    elems: self.iter_all().cloned().collect(),
+                    dim: self.dim,
+                }
+            }
+        }
+
+        impl<T, P: Pos> Index<Vec2<P>> for $view<'_, T> {
+            type Output = T;
+
+            #[track_caller]
+            fn index(&self, index: Vec2<P>) -> &Self::Output {
+                &self.elems[self.pos_in_bounds(index)]
+            }
+        }
+    };
+}
+
+impl_view!(View);
+impl_view!(ViewMut);
+
+impl<'a, T> View<'a, T> {
+    /// Returns a view of the sub-region with its top-left corner at `pos` and the given dimensions.
+    ///
+    /// Panics if the window would be empty or would not fit inside the view.
+    #[track_caller]
+    pub fn window<P: Pos>(self, pos: Vec2<P>, dim: Vec2<P>) -> Self {
+        let (base, dim) = self.window_params(pos, dim);
+        Self { base, dim, ..self }
+    }
+
+    /// Returns a view of every `step.x`th column and `step.y`th row, starting from the top-left corner.
+    ///
+    /// Panics if either component of `step` is zero.
+    #[track_caller]
+    pub fn strided<P: Pos>(self, step: Vec2<P>) -> Self {
+        let (stride, dim) = self.strided_params(step);
+        Self {
+            stride,
+            dim,
+            ..self
+        }
+    }
+}
+
+impl<'a, T> ViewMut<'a, T> {
+    /// Returns a shared view of the same region.
+    #[inline]
+    pub fn as_view(&self) -> View<'_, T> {
+        View {
+            elems: self.elems,
+            base: self.base,
+            stride: self.stride,
+            dim: self.dim,
+        }
+    }
+
+    /// Returns a mutable reference to the element at the given position, or `None` if the position is out of bounds.
+    pub fn get_mut<P: Pos>(&mut self, pos: Vec2<P>) -> Option<&mut T> {
+        self.get_in_bounds(pos).map(|pos| {
+            let i = self.flat_index(pos);
+            &mut self.elems[i]
+        })
+    }
+
+    /// Iterator over mutable references to elements in row-major order.
+    pub fn iter_mut_all(&mut self) -> impl Iterator<Item = &mut T> {
+        let (stride, dim) = (self.stride, self.dim);
+        self.elems[self.base..]
+            .chunks_mut(stride.y)
+            .take(dim.y)
+            .flat_map(move |row| row.iter_mut().step_by(stride.x).take(dim.x))
+    }
+
+    /// Iterator over mutable references to elements and their positions in row-major order.
+    pub fn enumerate_mut<P: Pos>(&mut self) -> impl Iterator<Item = (Vec2<P>, &mut T)> {
+        Positions::new(self.dim).zip(self.iter_mut_all())
+    }
+
+    /// Returns a mutable view of the sub-region with its top-left corner at `pos` and the given dimensions.
+    ///
+    /// Panics if the window would be empty or would not fit inside the view.
+    #[track_caller]
+    pub fn window<P: Pos>(self, pos: Vec2<P>, dim: Vec2<P>) -> Self {
+        let (base, dim) = self.window_params(pos, dim);
+        Self { base, dim, ..self }
+    }
+
+    /// Returns a mutable view of every `step.x`th column and `step.y`th row, starting from the top-left corner.
+    ///
+    /// Panics if either component of `step` is zero.
+    #[track_caller]
+    pub fn strided<P: Pos>(self, step: Vec2<P>) -> Self {
+        let (stride, dim) = self.strided_params(step);
+        Self {
+            stride,
+            dim,
+            ..self
+        }
+    }
+}
+
+impl<T: Clone> ViewMut<'_, T> {
+    /// Sets every element in the view to the given value.
+    pub fn fill(&mut self, value: T) {
+        for e in self.iter_mut_all() {
+            *e = value.clone();
+        }
+    }
+}
+
+impl<T, P: Pos> IndexMut<Vec2<P>> for ViewMut<'_, T> {
+    #[track_caller]
+    fn index_mut(&mut self, index: Vec2<P>) -> &mut Self::Output {
+        let i = self.pos_in_bounds(index);
+        &mut self.elems[i]
+    }
+}
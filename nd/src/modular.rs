@@ -0,0 +1,392 @@
+use std::{
+    fmt,
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
+};
+
+use num_traits::{One, Zero};
+
+use crate::traits::Pivot;
+
+/// An integer modulo `M`, for use in modular arithmetic and as a field element when `M` is prime.
+///
+/// `M` must be greater than `1`, which is checked at compile time.
+/// For a modulus only known at runtime, use [`mul_mod`], [`pow_mod`], [`inverse_mod`] and [`crt`] instead.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Mod<const M: u64> {
+    value: u64,
+}
+
+impl<const M: u64> Mod<M> {
+    /// Creates a new value by reducing `value` modulo `M`.
+    #[inline]
+    pub const fn new(value: u64) -> Self {
+        const { assert!(M > 1, "modulus must be greater than 1") };
+        Self { value: value % M }
+    }
+
+    /// Returns the canonical representative of `self`, in the range `0..M`.
+    #[inline(always)]
+    pub const fn value(self) -> u64 {
+        self.value
+    }
+
+    /// Returns the modulus `M`.
+    #[inline(always)]
+    pub const fn modulus() -> u64 {
+        M
+    }
+
+    /// Returns `self` raised to the given power.
+    pub fn pow(self, mut exponent: u64) -> Self {
+        let mut result = Self::one();
+        let mut base = self;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exponent >>= 1;
+        }
+        result
+    }
+
+    /// Returns the multiplicative inverse of `self`, or `None` if `self` is not coprime to `M`.
+    pub fn inv(self) -> Option<Self> {
+        let (gcd, inv, _) = extended_gcd(self.value as i128, M as i128);
+        (gcd == 1).then(|| Self::new(inv.rem_euclid(M as i128) as u64))
+    }
+
+    #[track_caller]
+    fn inv_or_panic(self) -> Self {
+        if let Some(inv) = self.inv() {
+            inv
+        } else {
+            panic!("{} has no inverse modulo {M}", self.value);
+        }
+    }
+}
+
+impl<const M: u64> From<u64> for Mod<M> {
+    #[inline]
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const M: u64> From<i64> for Mod<M> {
+    #[inline]
+    fn from(value: i64) -> Self {
+        Self::new((value as i128).rem_euclid(M as i128) as u64)
+    }
+}
+
+impl<const M: u64> From<Mod<M>> for u64 {
+    #[inline(always)]
+    fn from(value: Mod<M>) -> Self {
+        value.value
+    }
+}
+
+impl<const M: u64> Zero for Mod<M> {
+    #[inline(always)]
+    fn zero() -> Self {
+        Self { value: 0 }
+    }
+
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.value == 0
+    }
+}
+
+impl<const M: u64> One for Mod<M> {
+    #[inline(always)]
+    fn one() -> Self {
+        const { assert!(M > 1, "modulus must be greater than 1") };
+        Self::new(1)
+    }
+}
+
+impl<const M: u64> Pivot for Mod<M> {
+    #[inline]
+    fn better_pivot(&self, other: &Self) -> bool {
+        other.is_zero() && !self.is_zero()
+    }
+}
+
+impl<const M: u64> Neg for Mod<M> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::new(M - self.value)
+    }
+}
+
+impl<const M: u64> Neg for &Mod<M> {
+    type Output = Mod<M>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+impl<const M: u64> Add for Mod<M> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            value: ((self.value as u128 + rhs.value as u128) % M as u128) as u64,
+        }
+    }
+}
+
+impl<const M: u64> Sub for Mod<M> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl<const M: u64> Mul for Mod<M> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            value: ((self.value as u128 * rhs.value as u128) % M as u128) as u64,
+        }
+    }
+}
+
+impl<const M: u64> Div for Mod<M> {
+    type Output = Self;
+
+    /// Multiplies by the inverse of `rhs`.
+    ///
+    /// Panics if `rhs` is not coprime to `M`.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    #[track_caller]
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.inv_or_panic()
+    }
+}
+
+/// Takes the remainder of the canonical representatives, as for the underlying integers,
+/// so that [`Matrix::pow_mod`](crate::Matrix::pow_mod) reduces elements as expected.
+impl<const M: u64> Rem for Mod<M> {
+    type Output = Self;
+
+    /// Panics if `rhs` is zero.
+    #[track_caller]
+    #[inline]
+    fn rem(self, rhs: Self) -> Self::Output {
+        Self::new(self.value % rhs.value)
+    }
+}
+
+macro_rules! impl_op {
+    ($tr:ident, $f:ident, $tr_a:ident, $f_a:ident) => {
+        impl<const M: u64> $tr<&Mod<M>> for Mod<M> {
+            type Output = Mod<M>;
+
+            #[track_caller]
+            #[inline(always)]
+            fn $f(self, rhs: &Mod<M>) -> Self::Output {
+                $tr::$f(self, *rhs)
+            }
+        }
+
+        impl<const M: u64> $tr<Mod<M>> for &Mod<M> {
+            type Output = Mod<M>;
+
+            #[track_caller]
+            #[inline(always)]
+            fn $f(self, rhs: Mod<M>) -> Self::Output {
+                $tr::$f(*self, rhs)
+            }
+        }
+
+        impl<const M: u64> $tr<&Mod<M>> for &Mod<M> {
+            type Output = Mod<M>;
+
+            #[track_caller]
+            #[inline(always)]
+            fn $f(self, rhs: &Mod<M>) -> Self::Output {
+                $tr::$f(*self, *rhs)
+            }
+        }
+
+        impl<const M: u64> $tr_a for Mod<M> {
+            #[track_caller]
+            #[inline(always)]
+            fn $f_a(&mut self, rhs: Mod<M>) {
+                *self = $tr::$f(*self, rhs);
+            }
+        }
+
+        impl<const M: u64> $tr_a<&Mod<M>> for Mod<M> {
+            #[track_caller]
+            #[inline(always)]
+            fn $f_a(&mut self, rhs: &Mod<M>) {
+                *self = $tr::$f(*self, *rhs);
+            }
+        }
+    };
+}
+
+impl_op!(Add, add, AddAssign, add_assign);
+impl_op!(Sub, sub, SubAssign, sub_assign);
+impl_op!(Mul, mul, MulAssign, mul_assign);
+impl_op!(Div, div, DivAssign, div_assign);
+impl_op!(Rem, rem, RemAssign, rem_assign);
+
+impl<const M: u64> Sum for Mod<M> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), Add::add)
+    }
+}
+
+impl<'a, const M: u64> Sum<&'a Mod<M>> for Mod<M> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), Add::add)
+    }
+}
+
+impl<const M: u64> Product for Mod<M> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), Mul::mul)
+    }
+}
+
+impl<'a, const M: u64> Product<&'a Mod<M>> for Mod<M> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), Mul::mul)
+    }
+}
+
+impl<const M: u64> fmt::Debug for Mod<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (mod {M})", self.value)
+    }
+}
+
+impl<const M: u64> fmt::Display for Mod<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+/// Returns `a * b` modulo `modulus`, without overflow.
+///
+/// Panics if `modulus` is `0`.
+#[track_caller]
+#[inline]
+pub fn mul_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    (a as u128 * b as u128 % modulus as u128) as u64
+}
+
+/// Returns `base` raised to the given power modulo `modulus`.
+///
+/// Panics if `modulus` is `0`.
+#[track_caller]
+pub fn pow_mod(base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1 % modulus;
+    let mut base = base % modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exponent >>= 1;
+    }
+    result
+}
+
+/// Returns the multiplicative inverse of `value` modulo `modulus`, in the range `0..modulus`,
+/// or `None` if `value` is not coprime to `modulus`.
+///
+/// Panics if `modulus` is not positive.
+#[track_caller]
+pub fn inverse_mod(value: i64, modulus: i64) -> Option<i64> {
+    assert!(modulus > 0, "modulus must be positive: {modulus}");
+    let (gcd, inv, _) = extended_gcd((value as i128).rem_euclid(modulus as i128), modulus as i128);
+    (gcd == 1).then(|| inv.rem_euclid(modulus as i128) as i64)
+}
+
+/// Solves the system of congruences `x = r (mod m)` for each `(r, m)` pair, using the Chinese remainder theorem.
+///
+/// The moduli do not need to be coprime. Returns the least non-negative solution along with
+/// the least common multiple of the moduli, or `None` if the congruences are inconsistent.
+///
+/// Panics if any modulus is not positive, or if the least common multiple does not fit in an `i64`.
+#[track_caller]
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Option<(i64, i64)> {
+    let (mut r, mut m) = (0i128, 1i128);
+    for (r2, m2) in congruences {
+        assert!(m2 > 0, "modulus must be positive: {m2}");
+        let (r2, m2) = ((r2 as i128).rem_euclid(m2 as i128), m2 as i128);
+        let (gcd, p, _) = extended_gcd(m, m2);
+        if (r2 - r) % gcd != 0 {
+            return None;
+        }
+        let step = m2 / gcd;
+        let k = ((r2 - r) / gcd % step * (p % step)).rem_euclid(step);
+        let lcm = m * step;
+        if i64::try_from(lcm).is_err() {
+            panic!("least common multiple of moduli does not fit in an i64: {lcm}");
+        }
+        r = (r + m * k).rem_euclid(lcm);
+        m = lcm;
+    }
+    Some((r as i64, m as i64))
+}
+
+/// Returns `(gcd, x, y)` such that `a * x + b * y = gcd`.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    (old_r, old_x, old_y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Matrix;
+
+    type Prime = Mod<1_000_000_007>;
+
+    #[test]
+    fn rem() {
+        assert_eq!(Prime::new(17) % Prime::new(5), Prime::new(2));
+        assert_eq!(Prime::from(-1i64) % Prime::new(10), Prime::new(6));
+    }
+
+    #[test]
+    fn matrix_pow_mod() {
+        let fib = |n| {
+            Matrix::new([
+                [Prime::new(1), Prime::new(1)],
+                [Prime::new(1), Prime::zero()],
+            ])
+            .pow_mod(n, &Prime::new(1000))[0][1]
+        };
+        assert_eq!(fib(10), Prime::new(55));
+        assert_eq!(fib(30), Prime::new(832_040 % 1000));
+        let plain = Matrix::new([[1u64, 1], [1, 0]]).pow_mod(1_000_000, &1000)[0][1];
+        assert_eq!(fib(1_000_000).value(), plain);
+    }
+}
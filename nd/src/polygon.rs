@@ -0,0 +1,106 @@
+use num_traits::Signed;
+
+use crate::{geom::reduce_2d, vector::Vec2};
+
+/// Returns twice the signed area of the polygon with the given vertices, using the shoelace formula.
+///
+/// The polygon is implicitly closed. The result is positive if the vertices are in anticlockwise order
+/// with `y` pointing up, or equivalently clockwise order with `y` pointing down as in [`Vec2::n`].
+pub fn double_area<T: Signed + Clone>(vertices: &[Vec2<T>]) -> T {
+    edges(vertices).fold(T::zero(), |acc, (a, b)| {
+        acc + a.x.clone() * b.y.clone() - a.y.clone() * b.x.clone()
+    })
+}
+
+/// Returns the area of the polygon with the given vertices.
+///
+/// The polygon is implicitly closed. For integer types, the result is rounded down.
+pub fn area<T: Signed + Clone>(vertices: &[Vec2<T>]) -> T {
+    double_area(vertices).abs() / (T::one() + T::one())
+}
+
+/// Returns the number of lattice steps around the boundary of the polygon with the given vertices,
+/// which is also the number of lattice points on the boundary.
+///
+/// The polygon is implicitly closed. Edges need not be axis-aligned.
+pub fn perimeter<T: Signed + Clone>(vertices: &[Vec2<T>]) -> T {
+    edges(vertices).fold(T::zero(), |acc, (a, b)| {
+        let delta = Vec2::new(b.x.clone() - a.x.clone(), b.y.clone() - a.y.clone());
+        acc + reduce_2d(&delta).1
+    })
+}
+
+/// Returns the number of lattice points strictly inside the polygon with the given integer vertices,
+/// using Pick's theorem.
+///
+/// The polygon is implicitly closed, and must not intersect itself.
+pub fn interior_points<T: Signed + Clone>(vertices: &[Vec2<T>]) -> T {
+    let two = T::one() + T::one();
+    (double_area(vertices).abs() - perimeter(vertices) + two.clone()) / two
+}
+
+/// Returns whether `point` lies on the boundary of the polygon with the given vertices.
+///
+/// The polygon is implicitly closed.
+pub fn on_boundary<T: Signed + Clone + PartialOrd>(vertices: &[Vec2<T>], point: &Vec2<T>) -> bool {
+    edges(vertices).any(|(a, b)| on_segment(a, b, point))
+}
+
+/// Returns whether `point` lies strictly inside the polygon with the given vertices.
+///
+/// The polygon is implicitly closed, and must not intersect itself.
+/// Points on the boundary are not inside.
+pub fn contains<T: Signed + Clone + PartialOrd>(vertices: &[Vec2<T>], point: &Vec2<T>) -> bool {
+    let mut inside = false;
+    for (a, b) in edges(vertices) {
+        if on_segment(a, b, point) {
+            return false;
+        }
+        if (a.y > point.y) != (b.y > point.y) {
+            let dy = b.y.clone() - a.y.clone();
+            let cross = (b.x.clone() - a.x.clone()) * (point.y.clone() - a.y.clone())
+                - (point.x.clone() - a.x.clone()) * dy.clone();
+            if (cross > T::zero()) == (dy > T::zero()) {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+/// Returns the vertices of the path that starts at `start` and moves `len` units in direction `dir`
+/// for each step `(dir, len)`, such as a list of `Dir4` and distances.
+///
+/// The first vertex is `start`. If the path ends at `start`, the final vertex is omitted,
+/// so that the result describes the implicitly closed polygon.
+pub fn from_steps<T, D>(start: Vec2<T>, steps: impl IntoIterator<Item = (D, T)>) -> Vec<Vec2<T>>
+where
+    T: Signed + Clone,
+    D: Into<Vec2<T>>,
+{
+    let mut vertices = vec![start.clone()];
+    let mut pos = start;
+    for (dir, len) in steps {
+        let dir = dir.into();
+        pos = Vec2::new(pos.x + dir.x * len.clone(), pos.y + dir.y * len);
+        vertices.push(pos.clone());
+    }
+    if vertices.len() > 1 && vertices.last() == vertices.first() {
+        vertices.pop();
+    }
+    vertices
+}
+
+fn edges<T>(vertices: &[Vec2<T>]) -> impl Iterator<Item = (&Vec2<T>, &Vec2<T>)> {
+    vertices.iter().zip(vertices.iter().cycle().skip(1))
+}
+
+fn on_segment<T: Signed + Clone + PartialOrd>(a: &Vec2<T>, b: &Vec2<T>, p: &Vec2<T>) -> bool {
+    let cross = (b.x.clone() - a.x.clone()) * (p.y.clone() - a.y.clone())
+        - (b.y.clone() - a.y.clone()) * (p.x.clone() - a.x.clone());
+    cross.is_zero() && between(&a.x, &b.x, &p.x) && between(&a.y, &b.y, &p.y)
+}
+
+fn between<T: PartialOrd>(a: &T, b: &T, x: &T) -> bool {
+    (a <= x && x <= b) || (b <= x && x <= a)
+}
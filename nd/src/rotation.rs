@@ -0,0 +1,251 @@
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    ops::{Mul, Neg, Sub},
+};
+
+use num_traits::{One, Zero};
+
+use crate::{matrix::Matrix, vector::Vec3};
+
+/// One of the three coordinate axes of 3D space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+/// One of the 24 rotations of 3D space that map each axis onto an axis.
+///
+/// Represented as a permutation of the axes along with a sign for each axis,
+/// so that component `i` of a rotated vector is component `perm[i]` of the original, negated if `neg[i]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rotation3 {
+    perm: [usize; 3],
+    neg: [bool; 3],
+}
+
+impl Rotation3 {
+    /// Returns the rotation that leaves every vector unchanged.
+    #[inline]
+    pub const fn identity() -> Self {
+        Self {
+            perm: [0, 1, 2],
+            neg: [false; 3],
+        }
+    }
+
+    /// Creates the rotation taking component `i` of a rotated vector from component `perm[i]` of the original,
+    /// negated if `neg[i]`.
+    ///
+    /// Panics if `perm` is not a permutation of `[0, 1, 2]`, or if the result would be a reflection.
+    #[track_caller]
+    pub fn new(perm: [usize; 3], neg: [bool; 3]) -> Self {
+        let mut seen = [false; 3];
+        for &p in &perm {
+            if p >= 3 || seen[p] {
+                panic!("not a permutation of the axes: {perm:?}");
+            }
+            seen[p] = true;
+        }
+        let rotation = Self { perm, neg };
+        if !rotation.is_proper() {
+            panic!("not a rotation: {perm:?} with negations {neg:?} is a reflection");
+        }
+        rotation
+    }
+
+    /// Returns the rotation anticlockwise by `quarter_turns` quarter turns about `axis`,
+    /// looking from the positive end of the axis towards the origin.
+    pub fn about(axis: Axis, quarter_turns: i32) -> Self {
+        let quarter = match axis {
+            Axis::X => Self {
+                perm: [0, 2, 1],
+                neg: [false, true, false],
+            },
+            Axis::Y => Self {
+                perm: [2, 1, 0],
+                neg: [false, false, true],
+            },
+            Axis::Z => Self {
+                perm: [1, 0, 2],
+                neg: [true, false, false],
+            },
+        };
+        (0..quarter_turns.rem_euclid(4)).fold(Self::identity(), |rotation, _| quarter * rotation)
+    }
+
+    /// Iterator over all 24 rotations, starting with the identity.
+    pub fn all() -> impl Iterator<Item = Self> {
+        const PERMS: [[usize; 3]; 6] = [
+            [0, 1, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [0, 2, 1],
+            [2, 1, 0],
+            [1, 0, 2],
+        ];
+        PERMS
+            .into_iter()
+            .flat_map(|perm| {
+                (0..8).map(move |bits| Self {
+                    perm,
+                    neg: [bits & 1 != 0, bits & 2 != 0, bits & 4 != 0],
+                })
+            })
+            .filter(Self::is_proper)
+    }
+
+    /// Returns the permutation of the axes.
+    #[inline(always)]
+    pub fn perm(&self) -> [usize; 3] {
+        self.perm
+    }
+
+    /// Returns which components are negated.
+    #[inline(always)]
+    pub fn neg(&self) -> [bool; 3] {
+        self.neg
+    }
+
+    /// Returns the rotation equivalent to applying `other` and then `self`.
+    pub fn compose(&self, other: &Self) -> Self {
+        Self {
+            perm: self.perm.map(|p| other.perm[p]),
+            neg: [0, 1, 2].map(|i| self.neg[i] != other.neg[self.perm[i]]),
+        }
+    }
+
+    /// Returns the rotation that undoes `self`.
+    pub fn inverse(&self) -> Self {
+        let mut inverse = Self::identity();
+        for i in 0..3 {
+            inverse.perm[self.perm[i]] = i;
+            inverse.neg[self.perm[i]] = self.neg[i];
+        }
+        inverse
+    }
+
+    /// Returns `vec` rotated by `self`.
+    pub fn apply<T: Clone + Neg<Output = T>>(&self, vec: &Vec3<T>) -> Vec3<T> {
+        let [x, y, z] = [0, 1, 2].map(|i| self.component(i, component(vec, self.perm[i]).clone()));
+        Vec3::new(x, y, z)
+    }
+
+    /// Returns a new matrix with each column of `matrix` rotated by `self`.
+    ///
+    /// Panics if `matrix` does not have 3 rows.
+    #[track_caller]
+    pub fn apply_matrix<T: Clone + Neg<Output = T>>(&self, matrix: &Matrix<T>) -> Matrix<T> {
+        let height = matrix.get_dim().y;
+        if height != 3 {
+            panic!("matrix does not have 3 rows: height is {height}");
+        }
+        Matrix::new([0, 1, 2].map(|i| {
+            matrix[self.perm[i]]
+                .iter()
+                .map(move |e| self.component(i, e.clone()))
+        }))
+    }
+
+    /// Returns the 3x3 matrix representing `self`.
+    pub fn to_matrix<T: Zero + One + Neg<Output = T>>(&self) -> Matrix<T> {
+        Matrix::new([0, 1, 2].map(|i| {
+            (0..3).map(move |j| {
+                if j == self.perm[i] {
+                    self.component(i, T::one())
+                } else {
+                    T::zero()
+                }
+            })
+        }))
+    }
+
+    /// Finds a rotation and translation which, applied to the points in `b`,
+    /// make at least `min_matches` of them coincide with points in `a`.
+    ///
+    /// Returns the rotation and translation such that `rotation.apply(p) + translation` is in `a`
+    /// for the matching points `p`, or `None` if there are none.
+    pub fn align<T>(a: &[Vec3<T>], b: &[Vec3<T>], min_matches: usize) -> Option<(Self, Vec3<T>)>
+    where
+        T: Clone + Eq + Hash + Neg<Output = T> + Sub<Output = T>,
+    {
+        for rotation in Self::all() {
+            let rotated: Vec<_> = b.iter().map(|p| rotation.apply(p)).collect();
+            let mut votes = HashMap::new();
+            for p in a {
+                for q in &rotated {
+                    let translation = p.clone() - q.clone();
+                    let count = votes.entry(translation.clone()).or_insert(0);
+                    *count += 1;
+                    if *count >= min_matches {
+                        return Some((rotation, translation));
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    fn is_proper(&self) -> bool {
+        let even = matches!(self.perm, [0, 1, 2] | [1, 2, 0] | [2, 0, 1]);
+        let negations = self.neg.iter().filter(|&&n| n).count();
+        even == (negations % 2 == 0)
+    }
+
+    #[inline]
+    fn component<T: Neg<Output = T>>(&self, i: usize, value: T) -> T {
+        if self.neg[i] {
+            -value
+        } else {
+            value
+        }
+    }
+}
+
+impl Default for Rotation3 {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Mul for Rotation3 {
+    type Output = Self;
+
+    /// Returns the rotation equivalent to applying `rhs` and then `self`.
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.compose(&rhs)
+    }
+}
+
+impl<T: Clone + Neg<Output = T>> Mul<Vec3<T>> for Rotation3 {
+    type Output = Vec3<T>;
+
+    /// Returns `rhs` rotated by `self`.
+    #[inline]
+    fn mul(self, rhs: Vec3<T>) -> Self::Output {
+        self.apply(&rhs)
+    }
+}
+
+impl<T: Clone + Neg<Output = T>> Mul<&Vec3<T>> for &Rotation3 {
+    type Output = Vec3<T>;
+
+    /// Returns `rhs` rotated by `self`.
+    #[inline]
+    fn mul(self, rhs: &Vec3<T>) -> Self::Output {
+        self.apply(rhs)
+    }
+}
+
+#[inline]
+fn component<T>(vec: &Vec3<T>, i: usize) -> &T {
+    match i {
+        0 => &vec.x,
+        1 => &vec.y,
+        _ => &vec.z,
+    }
+}
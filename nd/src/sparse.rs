@@ -0,0 +1,245 @@
+use std::{
+    collections::{hash_map, HashMap},
+    hash::Hash,
+    ops::{Add, Sub},
+};
+
+use num_traits::One;
+
+use crate::{
+    aabb::Aabb,
+    matrix::Matrix,
+    traits::{Coords, Pos},
+    vector::Vec2,
+};
+
+/// A grid storing elements only at occupied positions, for grids with no fixed bounds.
+///
+/// Positions are vectors such as `Vec2<P>` or `Vec3<P>`.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<P, T> {
+    elems: HashMap<P, T>,
+}
+
+impl<P, T> SparseGrid<P, T> {
+    /// Creates a new empty grid.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            elems: HashMap::new(),
+        }
+    }
+
+    /// Returns the number of occupied positions.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.elems.len()
+    }
+
+    /// Returns whether there are no occupied positions.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.elems.is_empty()
+    }
+
+    /// Iterator over the occupied positions and their elements, in arbitrary order.
+    #[inline]
+    pub fn iter(&self) -> hash_map::Iter<'_, P, T> {
+        self.elems.iter()
+    }
+
+    /// Iterator over the occupied positions and mutable references to their elements, in arbitrary order.
+    #[inline]
+    pub fn iter_mut(&mut self) -> hash_map::IterMut<'_, P, T> {
+        self.elems.iter_mut()
+    }
+
+    /// Iterator over the occupied positions, in arbitrary order.
+    #[inline]
+    pub fn positions(&self) -> hash_map::Keys<'_, P, T> {
+        self.elems.keys()
+    }
+
+    /// Removes every element.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.elems.clear();
+    }
+}
+
+impl<P: Eq + Hash, T> SparseGrid<P, T> {
+    /// Returns a shared reference to the element at `pos`, or `None` if it is unoccupied.
+    #[inline]
+    pub fn get(&self, pos: &P) -> Option<&T> {
+        self.elems.get(pos)
+    }
+
+    /// Returns a mutable reference to the element at `pos`, or `None` if it is unoccupied.
+    #[inline]
+    pub fn get_mut(&mut self, pos: &P) -> Option<&mut T> {
+        self.elems.get_mut(pos)
+    }
+
+    /// Returns whether `pos` is occupied.
+    #[inline]
+    pub fn contains(&self, pos: &P) -> bool {
+        self.elems.contains_key(pos)
+    }
+
+    /// Places `value` at `pos`, returning the element previously there, if any.
+    #[inline]
+    pub fn insert(&mut self, pos: P, value: T) -> Option<T> {
+        self.elems.insert(pos, value)
+    }
+
+    /// Removes and returns the element at `pos`, if any.
+    #[inline]
+    pub fn remove(&mut self, pos: &P) -> Option<T> {
+        self.elems.remove(pos)
+    }
+
+    /// Returns the entry at `pos` for in-place manipulation.
+    #[inline]
+    pub fn entry(&mut self, pos: P) -> hash_map::Entry<'_, P, T> {
+        self.elems.entry(pos)
+    }
+
+    /// Iterator over the occupied positions found by adding each offset to `pos`, along with their elements.
+    pub fn neighbours<'a>(
+        &'a self,
+        pos: &'a P,
+        offsets: impl IntoIterator<Item = P> + 'a,
+    ) -> impl Iterator<Item = (P, &'a T)> + 'a
+    where
+        P: Clone + Add<Output = P>,
+    {
+        offsets.into_iter().filter_map(move |offset| {
+            let neighbour = pos.clone() + offset;
+            self.get(&neighbour).map(|e| (neighbour, e))
+        })
+    }
+
+    /// Returns the number of occupied positions found by adding each offset to `pos`.
+    pub fn count_neighbours(&self, pos: &P, offsets: impl IntoIterator<Item = P>) -> usize
+    where
+        P: Clone + Add<Output = P>,
+    {
+        offsets
+            .into_iter()
+            .map(|offset| pos.clone() + offset)
+            .filter(|neighbour| self.contains(neighbour))
+            .count()
+    }
+}
+
+impl<P: Coords, T> SparseGrid<P, T>
+where
+    P::Scalar: Ord + Clone + One + Add<Output = P::Scalar>,
+{
+    /// Returns the smallest box containing every occupied position, or `None` if the grid is empty.
+    pub fn bounds(&self) -> Option<Aabb<P>> {
+        let mut positions = self.elems.keys();
+        let first = positions.next()?.clone();
+        let (mut min, mut max) = (first.clone(), first);
+        for pos in positions {
+            for axis in 0..P::DIM {
+                let value = pos.axis(axis);
+                if value < min.axis(axis) {
+                    *min.axis_mut(axis) = value.clone();
+                }
+                if value > max.axis(axis) {
+                    *max.axis_mut(axis) = value.clone();
+                }
+            }
+        }
+        Some(Aabb::from_inclusive(min, max))
+    }
+}
+
+impl<Q, T> SparseGrid<Vec2<Q>, T>
+where
+    Q: Pos + Ord + Hash + One + Add<Output = Q> + Sub<Output = Q>,
+{
+    /// Returns a dense matrix covering the bounding box of the grid, with unoccupied positions set to `fill`,
+    /// along with the position in the grid of the top-left corner of the matrix,
+    /// or `None` if the grid is empty.
+    ///
+    /// The element at position `pos` in the grid is at position `pos - origin` in the matrix.
+    pub fn to_matrix(&self, fill: T) -> Option<(Matrix<T>, Vec2<Q>)>
+    where
+        T: Clone,
+    {
+        let bounds = self.bounds()?;
+        let origin = bounds.min.clone();
+        let mut matrix = Matrix::init(bounds.max - bounds.min, fill);
+        for (pos, e) in &self.elems {
+            matrix[pos.clone() - origin.clone()] = e.clone();
+        }
+        Some((matrix, origin))
+    }
+
+    /// Renders the bounding box of the grid as lines of text,
+    /// mapping each element, or `None` for unoccupied positions, to a character.
+    ///
+    /// Returns an empty string if the grid is empty.
+    pub fn render(&self, mut f: impl FnMut(Option<&T>) -> char) -> String {
+        let Some(bounds) = self.bounds() else {
+            return String::new();
+        };
+        let origin = bounds.min.clone();
+        let mut matrix = Matrix::init(bounds.max - bounds.min, None);
+        for (pos, e) in &self.elems {
+            matrix[pos.clone() - origin.clone()] = Some(e);
+        }
+        matrix.render(|e| f(*e))
+    }
+}
+
+impl<P: Eq + Hash, T: PartialEq> PartialEq for SparseGrid<P, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.elems == other.elems
+    }
+}
+
+impl<P: Eq + Hash, T: Eq> Eq for SparseGrid<P, T> {}
+
+impl<P, T> Default for SparseGrid<P, T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: Eq + Hash, T> Extend<(P, T)> for SparseGrid<P, T> {
+    fn extend<I: IntoIterator<Item = (P, T)>>(&mut self, iter: I) {
+        self.elems.extend(iter);
+    }
+}
+
+impl<P: Eq + Hash, T> FromIterator<(P, T)> for SparseGrid<P, T> {
+    fn from_iter<I: IntoIterator<Item = (P, T)>>(iter: I) -> Self {
+        Self {
+            elems: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<P, T> IntoIterator for SparseGrid<P, T> {
+    type Item = (P, T);
+    type IntoIter = hash_map::IntoIter<P, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.elems.into_iter()
+    }
+}
+
+impl<'a, P, T> IntoIterator for &'a SparseGrid<P, T> {
+    type Item = (&'a P, &'a T);
+    type IntoIter = hash_map::Iter<'a, P, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.elems.iter()
+    }
+}
@@ -3,7 +3,7 @@ use std::{
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
 };
 
-use num_traits::{One, Zero};
+use num_traits::{One, Signed, Zero};
 
 /// Trait for types supporting arithmetic operations returning `Output`.
 pub trait FieldOps<Output = Self>:
@@ -54,6 +54,22 @@ pub trait Field: Clone + Zero + One + FieldOps + FieldAssign {}
 
 impl<T> Field for T where T: Clone + Zero + One + FieldOps + FieldAssign {}
 
+/// Trait for field types that can be used as pivots in Gaussian elimination.
+pub trait Pivot: Field {
+    /// Returns whether `self` would make a better pivot than `other`.
+    fn better_pivot(&self, other: &Self) -> bool;
+}
+
+impl<T> Pivot for T
+where
+    T: Field + Signed + PartialOrd,
+{
+    #[inline]
+    fn better_pivot(&self, other: &Self) -> bool {
+        self.abs() > other.abs()
+    }
+}
+
 /// Trait for types that can be used to index a collection via conversion to and from `usize`.
 pub trait Pos: fmt::Debug + Clone + TryInto<usize> + TryFrom<usize> {
     fn get_index(&self) -> Option<usize> {
@@ -84,3 +100,22 @@ pub trait Pos: fmt::Debug + Clone + TryInto<usize> + TryFrom<usize> {
 }
 
 impl<I> Pos for I where I: fmt::Debug + Clone + TryInto<usize> + TryFrom<usize> {}
+
+/// Trait for fixed-size vector types whose components can be accessed by axis.
+pub trait Coords: Clone {
+    /// The type of each component.
+    type Scalar;
+
+    /// The number of components.
+    const DIM: usize;
+
+    /// Returns a shared reference to the component along `axis`.
+    ///
+    /// Panics if `axis` is not less than `DIM`.
+    fn axis(&self, axis: usize) -> &Self::Scalar;
+
+    /// Returns a mutable reference to the component along `axis`.
+    ///
+    /// Panics if `axis` is not less than `DIM`.
+    fn axis_mut(&mut self, axis: usize) -> &mut Self::Scalar;
+}
@@ -1,6 +1,7 @@
 mod constants;
 mod linalg;
 mod ops;
+mod vecn;
 
 use std::{
     borrow::Borrow,
@@ -13,8 +14,14 @@ use num_traits::{
     Signed,
 };
 
+use crate::traits::Coords;
+
+pub use vecn::VecN;
+
 /// A 2D vector type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
 pub struct Vec2<T> {
     pub x: T,
     pub y: T,
@@ -101,6 +108,8 @@ impl<T: Ord> Vec2<T> {
 
 /// A 3D vector type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
 pub struct Vec3<T> {
     pub x: T,
     pub y: T,
@@ -200,6 +209,8 @@ impl<T: Ord> Vec3<T> {
 
 /// A 4D vector type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
 pub struct Vec4<T> {
     pub x: T,
     pub y: T,
@@ -336,6 +347,53 @@ impl<T: fmt::Display> fmt::Display for Vec4<T> {
     }
 }
 
+macro_rules! impl_coords {
+    ($vec:ident, $dim:literal, $($axis:literal => $field:ident),+) => {
+        impl<T: Clone> Coords for $vec<T> {
+            type Scalar = T;
+
+            const DIM: usize = $dim;
+
+            #[track_caller]
+            #[inline]
+            fn axis(&self, axis: usize) -> &T {
+                match axis {
+                    $($axis => &self.$field,)+
+                    _ => panic!("axis out of bounds: dimension is {} but axis is {axis}", $dim),
+                }
+            }
+
+            #[track_caller]
+            #[inline]
+            fn axis_mut(&mut self, axis: usize) -> &mut T {
+                match axis {
+                    $($axis => &mut self.$field,)+
+                    _ => panic!("axis out of bounds: dimension is {} but axis is {axis}", $dim),
+                }
+            }
+        }
+    };
+}
+
+impl_coords!(Vec2, 2, 0 => x, 1 => y);
+impl_coords!(Vec3, 3, 0 => x, 1 => y, 2 => z);
+impl_coords!(Vec4, 4, 0 => x, 1 => y, 2 => z, 3 => w);
+
+// SAFETY: each vector is `repr(C)` with fields all of type `T`, so it has no padding,
+// and it is valid for any bit pattern for which each of its fields is valid.
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for Vec2<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod> bytemuck::Pod for Vec2<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for Vec3<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod> bytemuck::Pod for Vec3<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for Vec4<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod> bytemuck::Pod for Vec4<T> {}
+
 fn abs_diff<'a, T>(a: &'a T, b: &'a T) -> T
 where
     &'a T: PartialOrd + Sub<Output = T>,
@@ -2,7 +2,7 @@
 
 use num_traits::{Signed, Zero};
 
-use crate::vector::{v, Vec2};
+use crate::vector::{v, v3, Vec2, Vec3};
 
 impl<T: Zero> Vec2<T> {
     #[inline(always)]
@@ -11,6 +11,13 @@ impl<T: Zero> Vec2<T> {
     }
 }
 
+impl<T: Zero> Vec3<T> {
+    #[inline(always)]
+    pub fn zero() -> Self {
+        v3(T::zero(), T::zero(), T::zero())
+    }
+}
+
 impl<T: Signed> Vec2<T> {
     #[inline(always)]
     pub fn e() -> Self {
@@ -3,6 +3,8 @@ use std::borrow::Borrow;
 use num_traits::{real::Real, Zero};
 
 use crate::{
+    dir::Turn,
+    rotation::{Axis, Rotation3},
     traits::{Field, FieldOps},
     vector::{v, Vec2, Vec3, Vec4},
     Matrix,
@@ -53,6 +55,26 @@ where
     pub fn perp(&self) -> Self {
         Self::new(-&self.y, self.x.clone())
     }
+
+    /// Returns `self` rotated anti-clockwise by `quarter_turns` quarter turns.
+    pub fn rotate_quarter(&self, quarter_turns: i32) -> Self {
+        match quarter_turns.rem_euclid(4) {
+            0 => self.clone(),
+            1 => self.perp(),
+            2 => -self,
+            _ => Self::new(self.y.clone(), -&self.x),
+        }
+    }
+
+    /// Returns `self` turned in the given direction,
+    /// where north is negative `y` as in [`Vec2::n`].
+    pub fn turn(&self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.rotate_quarter(3),
+            Turn::Right => self.perp(),
+            Turn::Reverse => -self,
+        }
+    }
 }
 
 impl<T: Field + Real> Vec2<T>
@@ -130,6 +152,11 @@ where
             &self.x * &other.y - &self.y * &other.x,
         )
     }
+
+    /// Returns `self` rotated anti-clockwise by `quarter_turns` quarter turns about `axis`.
+    pub fn rotate_quarter(&self, axis: Axis, quarter_turns: i32) -> Self {
+        Rotation3::about(axis, quarter_turns).apply(self)
+    }
 }
 
 impl<T: Field + Real> Vec3<T>
@@ -156,7 +183,35 @@ where
         self * (T::one() - t) + other * t
     }
 
-    // TODO: rotations about each axis
+    /// Returns `self` rotated anti-clockwise by `angle` radians about the `x` axis.
+    pub fn rotate_x(self, angle: T) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::new(
+            self.x,
+            self.y * cos - self.z * sin,
+            self.z * cos + self.y * sin,
+        )
+    }
+
+    /// Returns `self` rotated anti-clockwise by `angle` radians about the `y` axis.
+    pub fn rotate_y(self, angle: T) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::new(
+            self.x * cos + self.z * sin,
+            self.y,
+            self.z * cos - self.x * sin,
+        )
+    }
+
+    /// Returns `self` rotated anti-clockwise by `angle` radians about the `z` axis.
+    pub fn rotate_z(self, angle: T) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::new(
+            self.x * cos - self.y * sin,
+            self.y * cos + self.x * sin,
+            self.z,
+        )
+    }
 }
 
 impl<T: Clone> Vec4<T> {
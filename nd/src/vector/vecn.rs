@@ -0,0 +1,469 @@
+use std::{
+    array,
+    borrow::Borrow,
+    fmt,
+    ops::{
+        Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Rem, RemAssign, Sub,
+        SubAssign,
+    },
+};
+
+use num_traits::{
+    bounds::{LowerBounded, UpperBounded},
+    Signed, Zero,
+};
+
+use crate::{
+    traits::{Coords, Field, FieldOps},
+    vector::{abs_diff, Vec2, Vec3, Vec4},
+};
+
+/// An `N`-dimensional vector type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VecN<T, const N: usize> {
+    elems: [T; N],
+}
+
+impl<T, const N: usize> VecN<T, N> {
+    /// Creates a new vector with the given components.
+    #[inline(always)]
+    pub const fn new(elems: [T; N]) -> Self {
+        Self { elems }
+    }
+
+    /// Returns the number of components, `N`.
+    #[inline(always)]
+    pub const fn dim() -> usize {
+        N
+    }
+
+    /// Returns a shared reference to the components.
+    #[inline(always)]
+    pub fn as_array(&self) -> &[T; N] {
+        &self.elems
+    }
+
+    /// Returns a mutable reference to the components.
+    #[inline(always)]
+    pub fn as_mut_array(&mut self) -> &mut [T; N] {
+        &mut self.elems
+    }
+
+    /// Returns the components.
+    #[inline(always)]
+    pub fn into_array(self) -> [T; N] {
+        self.elems
+    }
+
+    /// Iterator over shared references to the components.
+    #[inline(always)]
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.elems.iter()
+    }
+
+    /// Iterator over mutable references to the components.
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.elems.iter_mut()
+    }
+
+    /// Returns a new vector with `f` applied to each component.
+    #[inline]
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> VecN<U, N> {
+        VecN::new(self.elems.map(f))
+    }
+
+    fn zip_with<U>(self, other: Self, mut f: impl FnMut(T, T) -> U) -> VecN<U, N> {
+        let mut other = other.elems.into_iter();
+        self.map(|a| f(a, other.next().unwrap()))
+    }
+}
+
+impl<T: Zero, const N: usize> VecN<T, N> {
+    /// Returns the zero vector.
+    #[inline]
+    pub fn zero() -> Self {
+        Self::new(array::from_fn(|_| T::zero()))
+    }
+}
+
+impl<T: LowerBounded, const N: usize> VecN<T, N> {
+    /// Returns a vector with the minimum value in every component.
+    #[inline]
+    pub fn min_value() -> Self {
+        Self::new(array::from_fn(|_| T::min_value()))
+    }
+}
+
+impl<T: UpperBounded, const N: usize> VecN<T, N> {
+    /// Returns a vector with the maximum value in every component.
+    #[inline]
+    pub fn max_value() -> Self {
+        Self::new(array::from_fn(|_| T::max_value()))
+    }
+}
+
+impl<T: Signed, const N: usize> VecN<T, N> {
+    /// Computes the absolute value component-wise.
+    pub fn abs(&self) -> Self {
+        Self::new(array::from_fn(|i| self.elems[i].abs()))
+    }
+
+    /// Computes the signum component-wise.
+    pub fn signum(&self) -> Self {
+        Self::new(array::from_fn(|i| self.elems[i].signum()))
+    }
+
+    /// Returns the unit vector along the given axis.
+    ///
+    /// Panics if `axis` is not less than `N`.
+    #[track_caller]
+    pub fn unit(axis: usize) -> Self {
+        if axis >= N {
+            panic!("axis out of bounds: dimension is {N} but axis is {axis}");
+        }
+        Self::new(array::from_fn(
+            |i| if i == axis { T::one() } else { T::zero() },
+        ))
+    }
+
+    /// Returns the `2N` offsets to orthogonally adjacent positions,
+    /// as the positive then negative unit vector along each axis in turn.
+    pub fn orth() -> Vec<Self> {
+        (0..N)
+            .flat_map(|axis| [Self::unit(axis), -Self::unit(axis)])
+            .collect()
+    }
+
+    /// Returns the `2N + 1` offsets to orthogonally adjacent positions and the zero vector,
+    /// with the zero vector first.
+    pub fn orth_z() -> Vec<Self> {
+        let mut offsets = vec![Self::zero()];
+        offsets.extend(Self::orth());
+        offsets
+    }
+
+    /// Returns the `3^N - 1` offsets to orthogonally or diagonally adjacent positions,
+    /// in lexicographic order.
+    pub fn adj() -> Vec<Self> {
+        Self::adj_z()
+            .into_iter()
+            .filter(|offset| offset.iter().any(|e| !e.is_zero()))
+            .collect()
+    }
+
+    /// Returns the `3^N` offsets to orthogonally or diagonally adjacent positions and the zero vector,
+    /// in lexicographic order.
+    pub fn adj_z() -> Vec<Self> {
+        let count = 3usize.pow(N as u32);
+        (0..count)
+            .map(|mut n| {
+                let mut digits = [0; N];
+                for digit in digits.iter_mut().rev() {
+                    *digit = n % 3;
+                    n /= 3;
+                }
+                Self::new(digits.map(|digit| match digit {
+                    0 => -T::one(),
+                    1 => T::zero(),
+                    _ => T::one(),
+                }))
+            })
+            .collect()
+    }
+}
+
+impl<T, const N: usize> VecN<T, N>
+where
+    for<'a> &'a T: PartialOrd + Sub<Output = T>,
+    T: Add<Output = T> + Zero,
+{
+    /// Calculates the absolute difference between `self` and `other` component-wise.
+    pub fn abs_diff(&self, other: impl Borrow<Self>) -> Self {
+        let other = other.borrow();
+        Self::new(array::from_fn(|i| {
+            abs_diff(&self.elems[i], &other.elems[i])
+        }))
+    }
+
+    /// Returns the manhattan distance between `self` and `other`.
+    pub fn manhattan(&self, other: impl Borrow<Self>) -> T {
+        self.abs_diff(other)
+            .elems
+            .into_iter()
+            .fold(T::zero(), |acc, e| acc + e)
+    }
+}
+
+impl<T: Ord, const N: usize> VecN<T, N> {
+    /// Computes the minimum of `self` and `other` component-wise.
+    pub fn min(self, other: Self) -> Self {
+        self.zip_with(other, Ord::min)
+    }
+
+    /// Computes the maximum of `self` and `other` component-wise.
+    pub fn max(self, other: Self) -> Self {
+        self.zip_with(other, Ord::max)
+    }
+
+    /// Clamps `self` between `min` and `max` component-wise.
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        self.max(min).min(max)
+    }
+}
+
+impl<T: Field, const N: usize> VecN<T, N>
+where
+    for<'a> &'a T: FieldOps<T>,
+{
+    /// Returns the dot product of `self` and `other`.
+    pub fn dot(&self, other: impl Borrow<Self>) -> T {
+        let other = other.borrow();
+        self.iter()
+            .zip(other.iter())
+            .fold(T::zero(), |acc, (a, b)| acc + a * b)
+    }
+
+    /// Returns the square of the magnitude of `self`.
+    pub fn len_sq(&self) -> T {
+        self.dot(self)
+    }
+}
+
+impl<T: Clone, const N: usize> Coords for VecN<T, N> {
+    type Scalar = T;
+
+    const DIM: usize = N;
+
+    #[track_caller]
+    #[inline(always)]
+    fn axis(&self, axis: usize) -> &T {
+        &self.elems[axis]
+    }
+
+    #[track_caller]
+    #[inline(always)]
+    fn axis_mut(&mut self, axis: usize) -> &mut T {
+        &mut self.elems[axis]
+    }
+}
+
+impl<T: Default, const N: usize> Default for VecN<T, N> {
+    fn default() -> Self {
+        Self::new(array::from_fn(|_| T::default()))
+    }
+}
+
+impl<T, const N: usize> Index<usize> for VecN<T, N> {
+    type Output = T;
+
+    #[track_caller]
+    #[inline(always)]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.elems[index]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for VecN<T, N> {
+    #[track_caller]
+    #[inline(always)]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.elems[index]
+    }
+}
+
+impl<T, const N: usize> IntoIterator for VecN<T, N> {
+    type Item = T;
+    type IntoIter = array::IntoIter<T, N>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.elems.into_iter()
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for VecN<T, N> {
+    #[inline(always)]
+    fn from(value: [T; N]) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T, const N: usize> From<VecN<T, N>> for [T; N] {
+    #[inline(always)]
+    fn from(value: VecN<T, N>) -> Self {
+        value.elems
+    }
+}
+
+impl<T> From<Vec2<T>> for VecN<T, 2> {
+    #[inline]
+    fn from(value: Vec2<T>) -> Self {
+        Self::new([value.x, value.y])
+    }
+}
+
+impl<T> From<VecN<T, 2>> for Vec2<T> {
+    #[inline]
+    fn from(value: VecN<T, 2>) -> Self {
+        let [x, y] = value.elems;
+        Self::new(x, y)
+    }
+}
+
+impl<T> From<Vec3<T>> for VecN<T, 3> {
+    #[inline]
+    fn from(value: Vec3<T>) -> Self {
+        Self::new([value.x, value.y, value.z])
+    }
+}
+
+impl<T> From<VecN<T, 3>> for Vec3<T> {
+    #[inline]
+    fn from(value: VecN<T, 3>) -> Self {
+        let [x, y, z] = value.elems;
+        Self::new(x, y, z)
+    }
+}
+
+impl<T> From<Vec4<T>> for VecN<T, 4> {
+    #[inline]
+    fn from(value: Vec4<T>) -> Self {
+        Self::new([value.x, value.y, value.z, value.w])
+    }
+}
+
+impl<T> From<VecN<T, 4>> for Vec4<T> {
+    #[inline]
+    fn from(value: VecN<T, 4>) -> Self {
+        let [x, y, z, w] = value.elems;
+        Self::new(x, y, z, w)
+    }
+}
+
+impl<T: fmt::Display, const N: usize> fmt::Display for VecN<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        for (i, e) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{e}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+macro_rules! impl_term {
+    ($tr:ident, $f:ident, $op:tt, $tr_a:ident, $f_a:ident, $op_a:tt) => {
+        impl<'a, 'b, T, const N: usize> $tr<&'b VecN<T, N>> for &'a VecN<T, N>
+        where
+            &'a T: $tr<&'b T, Output = T>,
+        {
+            type Output = VecN<T, N>;
+
+            #[track_caller]
+            fn $f(self, rhs: &'b VecN<T, N>) -> Self::Output {
+                VecN::new(array::from_fn(|i| &self.elems[i] $op &rhs.elems[i]))
+            }
+        }
+
+        impl<'a, T: $tr_a<&'a T>, const N: usize> $tr_a<&'a VecN<T, N>> for VecN<T, N> {
+            #[track_caller]
+            fn $f_a(&mut self, rhs: &'a VecN<T, N>) {
+                for (a, b) in self.elems.iter_mut().zip(&rhs.elems) {
+                    *a $op_a b;
+                }
+            }
+        }
+
+        impl<T: $tr<Output = T>, const N: usize> $tr<VecN<T, N>> for VecN<T, N> {
+            type Output = VecN<T, N>;
+
+            #[track_caller]
+            fn $f(self, rhs: VecN<T, N>) -> Self::Output {
+                self.zip_with(rhs, |a, b| a $op b)
+            }
+        }
+
+        impl<T: $tr_a, const N: usize> $tr_a<VecN<T, N>> for VecN<T, N> {
+            #[track_caller]
+            fn $f_a(&mut self, rhs: VecN<T, N>) {
+                for (a, b) in self.elems.iter_mut().zip(rhs.elems) {
+                    *a $op_a b;
+                }
+            }
+        }
+    };
+}
+
+impl_term!(Add, add, +, AddAssign, add_assign, +=);
+impl_term!(Sub, sub, -, SubAssign, sub_assign, -=);
+
+macro_rules! impl_factor {
+    ($tr:ident, $f:ident, $op:tt, $tr_a:ident, $f_a:ident, $op_a:tt) => {
+        impl<'a, 'b, T, const N: usize> $tr<&'b T> for &'a VecN<T, N>
+        where
+            &'a T: $tr<&'b T, Output = T>,
+        {
+            type Output = VecN<T, N>;
+
+            #[track_caller]
+            fn $f(self, rhs: &'b T) -> Self::Output {
+                VecN::new(array::from_fn(|i| &self.elems[i] $op rhs))
+            }
+        }
+
+        impl<'a, T: $tr_a<&'a T>, const N: usize> $tr_a<&'a T> for VecN<T, N> {
+            #[track_caller]
+            fn $f_a(&mut self, rhs: &'a T) {
+                for a in &mut self.elems {
+                    *a $op_a rhs;
+                }
+            }
+        }
+
+        impl<T: Copy + $tr<Output = T>, const N: usize> $tr<T> for VecN<T, N> {
+            type Output = VecN<T, N>;
+
+            #[track_caller]
+            fn $f(self, rhs: T) -> Self::Output {
+                self.map(|a| a $op rhs)
+            }
+        }
+
+        impl<T: Copy + $tr_a, const N: usize> $tr_a<T> for VecN<T, N> {
+            #[track_caller]
+            fn $f_a(&mut self, rhs: T) {
+                for a in &mut self.elems {
+                    *a $op_a rhs;
+                }
+            }
+        }
+    };
+}
+
+impl_factor!(Mul, mul, *, MulAssign, mul_assign, *=);
+impl_factor!(Div, div, /, DivAssign, div_assign, /=);
+impl_factor!(Rem, rem, %, RemAssign, rem_assign, %=);
+
+impl<T: Neg<Output = T>, const N: usize> Neg for VecN<T, N> {
+    type Output = VecN<T, N>;
+
+    #[track_caller]
+    fn neg(self) -> Self::Output {
+        self.map(|a| -a)
+    }
+}
+
+impl<'a, T, const N: usize> Neg for &'a VecN<T, N>
+where
+    &'a T: Neg<Output = T>,
+{
+    type Output = VecN<T, N>;
+
+    #[track_caller]
+    fn neg(self) -> Self::Output {
+        VecN::new(array::from_fn(|i| -&self.elems[i]))
+    }
+}